@@ -1,6 +1,6 @@
 use eframe::{App, NativeOptions};
-use egui::{SliderOrientation, Window};
-use egui_double_slider::DoubleSlider;
+use egui::{Color32, SliderOrientation, Window};
+use egui_double_slider::{CollisionPolicy, DoubleSlider, Gradient, HandleShape, Marker};
 use egui_theme_switch::global_theme_switch;
 
 fn main() {
@@ -25,6 +25,8 @@ pub struct MyApp {
     slider_i32_high: i32,
     slider_f64_log_low: f64,
     slider_f64_log_high: f64,
+    slider_window_low: f64,
+    slider_window_high: f64,
     slider_vertical_low: f64,
     slider_vertical_high: f64,
 }
@@ -40,6 +42,8 @@ impl Default for MyApp {
             slider_i32_high: 40,
             slider_f64_log_low: 3e-4,
             slider_f64_log_high: 7e12,
+            slider_window_low: 0.0,
+            slider_window_high: 1024.0,
             slider_vertical_low: 15.0,
             slider_vertical_high: 40.0,
         }
@@ -51,62 +55,72 @@ impl App for MyApp {
         Window::new("Interactive Double Slider")
             .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
             .show(ui, |ui| {
-                let width = ui.available_width();
-
                 // Display slider, linked to the same range as the plot
-                ui.label("f32 values:");
+                ui.label(
+                    "f32 values (swap when crossing, double click to reset, right click for menu):",
+                );
                 ui.add(
                     DoubleSlider::new(
                         &mut self.slider_f32_low,
                         &mut self.slider_f32_high,
                         10.0..=300.0,
                     )
-                    .width(width)
-                    .separation_distance(0.0),
+                    .separation_distance(0.0)
+                    .collision_policy(CollisionPolicy::Swap)
+                    .reset_on_double_click(30.0, 200.0)
+                    .context_menu(true),
                 );
                 ui.label(format!("Lower Bound: {:.2}", self.slider_f32_low));
                 ui.label(format!("Upper Bound: {:.2}", self.slider_f32_high));
 
                 ui.separator();
-                ui.label("f64 values (inverted highlight):");
+                ui.label("f64 values (inverted highlight, snapping to a threshold marker):");
                 ui.add(
                     DoubleSlider::new(
                         &mut self.slider_f64_low,
                         &mut self.slider_f64_high,
                         10.0..=300.0,
                     )
-                    .width(width)
                     .separation_distance(10.0)
-                    .invert_highlighting(true),
+                    .invert_highlighting(true)
+                    .marker(Marker::new(120.0).label("threshold").color(Color32::RED))
+                    .snap_to_markers(6.0),
                 );
                 ui.label(format!("Lower Bound: {:.2}", self.slider_f64_low));
                 ui.label(format!("Upper Bound: {:.2}", self.slider_f64_high));
 
                 ui.separator();
-                ui.label("f64 values (cannot push by dragging):");
+                ui.label("f64 values (cannot push by dragging, color scale):");
                 ui.add(
                     DoubleSlider::new(
                         &mut self.slider_f64_low,
                         &mut self.slider_f64_high,
                         10.0..=300.0,
                     )
-                    .width(width)
                     .separation_distance(10.0)
-                    .push_by_dragging(false),
+                    .push_by_dragging(false)
+                    .track_gradient(Gradient::stops([
+                        (10.0, Color32::BLUE),
+                        (155.0, Color32::YELLOW),
+                        (300.0, Color32::RED),
+                    ])),
                 );
                 ui.label(format!("Lower Bound: {:.2}", self.slider_f64_low));
                 ui.label(format!("Upper Bound: {:.2}", self.slider_f64_high));
 
                 ui.separator();
-                ui.label("i32 values:");
+                ui.label("i32 values (span of at most 100, bracket handles, labels and ticks):");
                 ui.add(
                     DoubleSlider::new(
                         &mut self.slider_i32_low,
                         &mut self.slider_i32_high,
                         -150..=150,
                     )
-                    .width(width)
-                    .separation_distance(1),
+                    .separation_distance(1)
+                    .max_span(100)
+                    .handle_shape(HandleShape::Bracket)
+                    .value_labels(true)
+                    .ticks(7),
                 );
                 ui.label(format!("Lower Bound: {}", self.slider_i32_low));
                 ui.label(format!("Upper Bound: {}", self.slider_i32_high));
@@ -119,7 +133,6 @@ impl App for MyApp {
                         &mut self.slider_f64_log_high,
                         1e-10..=1e20,
                     )
-                    .width(width)
                     .vertical_scroll(false)
                     .separation_distance(1e-10)
                     .logarithmic(true)
@@ -129,6 +142,24 @@ impl App for MyApp {
                 ui.label(format!("Lower Bound: {:.3e}", self.slider_f64_log_low));
                 ui.label(format!("Upper Bound: {:.3e}", self.slider_f64_log_high));
 
+                ui.separator();
+                ui.label("f64 window with locked span and presets:");
+                ui.add(
+                    DoubleSlider::new(
+                        &mut self.slider_window_low,
+                        &mut self.slider_window_high,
+                        0.0..=8192.0,
+                    )
+                    .lock_span(true)
+                    .scroll_factor(1.0)
+                    .preset("Start", 0.0..=1024.0)
+                    .preset("Middle", 3584.0..=4608.0)
+                    .preset("End", 7168.0..=8192.0)
+                    .animate_presets(true),
+                );
+                ui.label(format!("Lower Bound: {:.2}", self.slider_window_low));
+                ui.label(format!("Upper Bound: {:.2}", self.slider_window_high));
+
                 ui.separator();
 
                 ui.horizontal(|ui| {
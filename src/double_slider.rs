@@ -1,16 +1,65 @@
-use egui::emath::{Numeric, Pos2, Rect, RectTransform, Vec2};
-use egui::epaint::{CircleShape, Color32, PathShape, RectShape, Shape, Stroke};
-use egui::{Sense, SliderOrientation, StrokeKind, Ui, Widget};
+use egui::emath::{Align2, Numeric, Pos2, Rect, RectTransform, Vec2};
+use egui::epaint::{Color32, RectShape, Shape, Stroke};
+use egui::{
+    ComboBox, DragValue, Event, FontId, Id, Key, Modifiers, Painter, Popup, PopupCloseBehavior,
+    Response, Sense, SliderOrientation, StrokeKind, TextStyle, Ui, ViewportCommand, Widget,
+};
 use std::ops::RangeInclusive;
 
+use crate::error::DoubleSliderError;
+use crate::gradient::Gradient;
+use crate::handle::{Handle, HandleShape, HandleState};
+use crate::history::SliderHistory;
+use crate::layout::SliderLayout;
+use crate::mapping::SliderMapping;
+use crate::marker::Marker;
+use crate::preset::{PresetAnimation, PresetDisplay};
+use crate::range_text;
+use crate::style::{DoubleSliderStyle, InteractionState};
+use crate::track::{TrackCap, TrackLayout};
+use crate::value;
+
 // offset for stroke highlight
 const OFFSET: f32 = 2.0;
 
+// how far the marks of the presets reach beyond the sides of the line
+const PRESET_MARKER_OVERHANG: f32 = 3.0;
+
+// width of the area around the line of a marker that shows its tooltip
+const MARKER_HOVER_WIDTH: f32 = 6.0;
+
+type AddContents<'a> = Box<dyn FnOnce(&mut Ui) + 'a>;
+type HandlePainter<'a> = Box<dyn Fn(&Painter, Rect, HandleState) + 'a>;
+
+/// What happens when a dragged control point runs into the other one.
+/// This also applies to the maximum span, see [`DoubleSlider::max_span`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CollisionPolicy {
+    /// The dragged control point pushes the other one along.
+    #[default]
+    Push,
+
+    /// The dragged control point is blocked by the other one.
+    Block,
+
+    /// The control points swap their roles when one is dragged past the other,
+    /// e.g. the dragged lower control point becomes the upper one. Otherwise it is blocked.
+    Swap,
+}
+
 /// Control two numbers with a double slider.
 ///
 /// The slider range defines the values you get when pulling the slider to the far edges.
 ///
 /// The range can include any numbers, and go from low-to-high or from high-to-low.
+/// The lower value is always at most the upper value, so with a high-to-low range
+/// its control point is on the right of a horizontal (or the top of a vertical) slider.
+///
+/// Whenever the slider is shown, both values are inside the range (never NaN) and rounded for integer types,
+/// at least the separation distance apart (or at the ends of the range if it is too short for that)
+/// and at most the maximum span apart.
+/// Values that do not satisfy this, e.g. after being set by the application, are corrected and reported as changed.
+/// Read-only and disabled sliders only draw the corrected values and leave the values of the application alone.
 ///
 ///
 /// ```
@@ -35,13 +84,45 @@ pub struct DoubleSlider<'a, T: Numeric> {
     scroll_factor: f32,
     zoom_factor: f32,
     slider_px_size: f32,
+    size: Option<f32>,
+    min_size: Option<f32>,
     color: Option<Color32>,
     cursor_fill: Option<Color32>,
     stroke: Option<Stroke>,
     range: RangeInclusive<T>,
     logarithmic: bool,
-    push_by_dragging: bool,
+    collision_policy: CollisionPolicy,
     orientation: SliderOrientation,
+    precision_modifier: Modifiers,
+    precision_factor: f32,
+    reset_values: Option<(T, T)>,
+    context_menu: bool,
+    context_menu_entries: Option<AddContents<'a>>,
+    clipboard: bool,
+    max_span: Option<T>,
+    lock_span: bool,
+    lower_handle_shape: HandleShape,
+    upper_handle_shape: HandleShape,
+    handle_painter: Option<HandlePainter<'a>>,
+    style: Option<DoubleSliderStyle>,
+    read_only: bool,
+    track_gradient: Option<Gradient<'a>>,
+    band_gradient: Option<Gradient<'a>>,
+    track_thickness: Option<f32>,
+    track_corner_radius: Option<f32>,
+    track_cap: TrackCap,
+    value_labels: bool,
+    ticks: usize,
+    min_decimals: usize,
+    max_decimals: Option<usize>,
+    significant_digits: Option<usize>,
+    history: bool,
+    presets: Vec<(String, T, T)>,
+    preset_display: PresetDisplay,
+    preset_markers: bool,
+    animate_presets: bool,
+    markers: Vec<Marker<T>>,
+    marker_snap_distance: Option<f32>,
 }
 
 impl<'a, T: Numeric> DoubleSlider<'a, T> {
@@ -57,31 +138,75 @@ impl<'a, T: Numeric> DoubleSlider<'a, T> {
             scroll_factor: if T::INTEGRAL { 0.04 } else { 0.01 },
             zoom_factor: 10.0,
             slider_px_size: 100.0,
+            size: None,
+            min_size: None,
             cursor_fill: None,
             color: None,
             stroke: None,
             range,
             logarithmic: false,
-            push_by_dragging: true,
+            collision_policy: CollisionPolicy::Push,
             orientation: SliderOrientation::Horizontal,
+            precision_modifier: Modifiers::SHIFT,
+            precision_factor: 0.1,
+            reset_values: None,
+            context_menu: false,
+            context_menu_entries: None,
+            clipboard: true,
+            max_span: None,
+            lock_span: false,
+            lower_handle_shape: HandleShape::Circle,
+            upper_handle_shape: HandleShape::Circle,
+            handle_painter: None,
+            style: None,
+            read_only: false,
+            track_gradient: None,
+            band_gradient: None,
+            track_thickness: None,
+            track_corner_radius: None,
+            track_cap: TrackCap::Extended,
+            value_labels: false,
+            ticks: 0,
+            min_decimals: 0,
+            max_decimals: None,
+            significant_digits: None,
+            history: false,
+            presets: Vec::new(),
+            preset_display: PresetDisplay::Chips,
+            preset_markers: true,
+            animate_presets: false,
+            markers: Vec::new(),
+            marker_snap_distance: None,
         }
     }
 
     /// Set the primary width for the slider.
-    /// Default is 100.0
+    /// Default is the available width (or height for a vertical slider), see [`Self::size`].
     /// Alias ```Self::size```
     #[inline]
     pub fn width(mut self, size: f32) -> Self {
-        self.slider_px_size = size;
+        self.size = Some(size);
         self
     }
 
     /// Set the primary size for the slider.
-    /// Default is 100.0
+    /// Default is the available width in a vertical or horizontally justified layout (or the height for a vertical slider
+    /// in a horizontal or vertically justified layout), like a [`egui::Separator`].
+    /// Otherwise, e.g. in [`Ui::horizontal`], if it is unbounded (e.g. the height in a [`egui::ScrollArea`])
+    /// or the [`Ui`] is sizing itself, `slider_width` in [`egui::style::Spacing`] is used instead, like [`egui::Slider`].
     /// Alias ```Self::width```
     #[inline]
     pub fn size(mut self, size: f32) -> Self {
-        self.slider_px_size = size;
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the minimum primary size for the slider, which also applies to a size set with [`Self::size`].
+    /// The slider is never smaller than the two control points.
+    /// Default is `interact_size.x` in [`egui::style::Spacing`].
+    #[inline]
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = Some(min_size);
         self
     }
 
@@ -133,6 +258,7 @@ impl<'a, T: Numeric> DoubleSlider<'a, T> {
     }
 
     /// Set the separation distance for the two sliders.
+    /// If the range is shorter than that, the values are kept at its ends.
     /// Default is 1.
     #[inline]
     pub fn separation_distance(mut self, separation_distance: T) -> Self {
@@ -140,6 +266,46 @@ impl<'a, T: Numeric> DoubleSlider<'a, T> {
         self
     }
 
+    /// Set the maximum distance between the two values, which must be at least the separation distance (see [`Self::validate`]).
+    /// Like the separation distance, it is kept by pushing the other value or blocking the dragged one (see [`Self::collision_policy`]).
+    /// Zooming out stops at the maximum span and pasted selections exceeding it are ignored.
+    /// Values set by the application that are farther apart are moved together around their center.
+    /// Default is no maximum.
+    #[inline]
+    pub fn max_span(mut self, max_span: T) -> Self {
+        self.max_span = Some(max_span);
+        self
+    }
+
+    /// Lock the distance between the two values, so that only the position of the selected window can change.
+    /// The window is moved by dragging it, clicking on the line (which centers the window there) or scrolling.
+    /// The control points are drawn as caps and cannot be dragged individually, zooming is disabled.
+    /// Resetting the values, entering them in the context menu and selecting a preset moves the window, pasted selections with a different span are ignored.
+    /// Default is false.
+    #[inline]
+    pub fn lock_span(mut self, lock_span: bool) -> Self {
+        self.lock_span = lock_span;
+        self
+    }
+
+    /// Show the values without allowing to change them.
+    /// Unlike a disabled slider (see [`Ui::add_enabled`]), it is drawn with the normal colors and still reacts to hovering, e.g. to show tooltips.
+    /// Default is false.
+    #[inline]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Set the colors and strokes of all parts of the slider, for each interaction state.
+    /// Default is the global style set with [`DoubleSliderStyle::set_global`], or [`DoubleSliderStyle::from_visuals`].
+    /// [`Self::color`], [`Self::stroke`] and [`Self::cursor_fill`] are applied on top of it.
+    #[inline]
+    pub fn style(mut self, style: DoubleSliderStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
     /// Set the primary color for the slider
     /// Default color is taken from `inactive.bg_fill` in [`egui::style::Widgets`], the same as [`egui::Slider`].
     #[inline]
@@ -149,7 +315,8 @@ impl<'a, T: Numeric> DoubleSlider<'a, T> {
     }
 
     /// Set the stroke for the main line.
-    /// Default width is 7.0 and default color is taken from `selection.bg_fill` in [`egui::Visuals`], the same as [`egui::Slider`]
+    /// The width sets the thickness of the line, unless [`Self::track_thickness`] is set.
+    /// Default width is `slider_rail_height` in [`egui::style::Spacing`] and default color is taken from `selection.bg_fill` in [`egui::Visuals`], the same as [`egui::Slider`]
     #[inline]
     pub fn stroke(mut self, stroke: Stroke) -> Self {
         self.stroke = Some(stroke);
@@ -164,6 +331,105 @@ impl<'a, T: Numeric> DoubleSlider<'a, T> {
         self
     }
 
+    /// Set the thickness of the main line and the highlighted part.
+    /// Default is `slider_rail_height` in [`egui::style::Spacing`], the same as [`egui::Slider`].
+    #[inline]
+    pub fn track_thickness(mut self, thickness: f32) -> Self {
+        self.track_thickness = Some(thickness);
+        self
+    }
+
+    /// Set the corner radius of the main line and the highlighted part, at most half of the thickness.
+    /// Default is the corner radius of `inactive` in [`egui::style::Widgets`], the same as [`egui::Slider`].
+    #[inline]
+    pub fn track_corner_radius(mut self, corner_radius: f32) -> Self {
+        self.track_corner_radius = Some(corner_radius);
+        self
+    }
+
+    /// Set where the main line ends.
+    /// Default is [`TrackCap::Extended`], the same as [`egui::Slider`].
+    #[inline]
+    pub fn track_cap(mut self, cap: TrackCap) -> Self {
+        self.track_cap = cap;
+        self
+    }
+
+    /// Show the values next to the control points, above a horizontal and to the right of a vertical slider.
+    /// They have as many decimals as can be set by dragging.
+    /// Default is false.
+    #[inline]
+    pub fn value_labels(mut self, value_labels: bool) -> Self {
+        self.value_labels = value_labels;
+        self
+    }
+
+    /// Show `count` ticks with labels, evenly spaced in the scale of the slider from the start to the end of the range,
+    /// below a horizontal and to the left of a vertical slider.
+    /// Default is 0.
+    #[inline]
+    pub fn ticks(mut self, count: usize) -> Self {
+        self.ticks = count;
+        self
+    }
+
+    /// Show at least `min_decimals` decimals in the labels of the values and the ticks, like [`egui::Slider::min_decimals`].
+    /// Default is 0.
+    #[inline]
+    pub fn min_decimals(mut self, min_decimals: usize) -> Self {
+        self.min_decimals = min_decimals;
+        self
+    }
+
+    /// Round the values to at most `max_decimals` decimals when they are changed by dragging, scrolling or zooming,
+    /// and show at most that many decimals in the labels, like [`egui::Slider::max_decimals`].
+    /// This keeps e.g. `f32` values at `30.0` instead of `30.000002` after scrolling.
+    /// Values set by the application are not rounded, and integer values are always rounded.
+    /// Default is no rounding.
+    #[inline]
+    pub fn max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    /// Round the values to exactly `decimals` decimals and always show that many, like [`egui::Slider::fixed_decimals`].
+    /// Shorthand for [`Self::min_decimals`] and [`Self::max_decimals`].
+    #[inline]
+    pub fn fixed_decimals(mut self, decimals: usize) -> Self {
+        self.min_decimals = decimals;
+        self.max_decimals = Some(decimals);
+        self
+    }
+
+    /// Round the values to `digits` significant digits when they are changed by dragging, scrolling or zooming,
+    /// e.g. `0.0012` and `1200.0` with 2 digits, and show at most that many digits in the labels.
+    /// This is useful for logarithmic scales, where the values span several orders of magnitude.
+    /// If [`Self::max_decimals`] is also set, the coarser rounding applies. Integer values are not rounded further.
+    /// Default is no rounding.
+    #[inline]
+    pub fn significant_digits(mut self, digits: usize) -> Self {
+        self.significant_digits = Some(digits);
+        self
+    }
+
+    /// Fill the main line with a gradient instead of a single color, e.g. to edit thresholds of a color scale.
+    /// The outline of the focused slider is still drawn on top of it.
+    /// Default is no gradient.
+    #[inline]
+    pub fn track_gradient(mut self, gradient: Gradient<'a>) -> Self {
+        self.track_gradient = Some(gradient);
+        self
+    }
+
+    /// Fill the highlighted part with a gradient instead of a single color.
+    /// The colors are the same as on the main line below it if the same gradient is used for both.
+    /// Default is no gradient.
+    #[inline]
+    pub fn band_gradient(mut self, gradient: Gradient<'a>) -> Self {
+        self.band_gradient = Some(gradient);
+        self
+    }
+
     /// Set the control point radius
     /// Default is 7.0
     #[inline]
@@ -172,136 +438,799 @@ impl<'a, T: Numeric> DoubleSlider<'a, T> {
         self
     }
 
-    /// Use a logarithmic scale.
+    /// Set the shape of both control points.
+    /// Default is [`HandleShape::Circle`].
+    #[inline]
+    pub fn handle_shape(mut self, shape: HandleShape) -> Self {
+        self.lower_handle_shape = shape;
+        self.upper_handle_shape = shape;
+        self
+    }
+
+    /// Set the shapes of the lower and upper control point separately, e.g. to indicate their direction.
+    /// Default is [`HandleShape::Circle`] for both.
+    #[inline]
+    pub fn handle_shapes(mut self, lower: HandleShape, upper: HandleShape) -> Self {
+        self.lower_handle_shape = lower;
+        self.upper_handle_shape = upper;
+        self
+    }
+
+    /// Paint the control points with a custom function instead of the built-in shapes.
+    /// It is called for each control point with the painter, the interactive area of the control point and its [`HandleState`].
+    #[inline]
+    pub fn paint_handle(mut self, paint_handle: impl Fn(&Painter, Rect, HandleState) + 'a) -> Self {
+        self.handle_painter = Some(Box::new(paint_handle));
+        self
+    }
+
+    /// Use a logarithmic scale, which requires a range of strictly positive values
+    /// (see [`DoubleSliderError::LogarithmicRange`]).
     /// Default is false.
     #[inline]
     pub fn logarithmic(mut self, logarithmic: bool) -> Self {
-        if logarithmic {
-            let range_f64 = self.range_f64();
-            assert!(
-                *range_f64.start() > 0.0 && range_f64.start().is_finite() &&
-                    *range_f64.end() > 0.0 && range_f64.end().is_finite(),
-                "Logarithmic scale can only be used with a range of finite, strictly positive values (both start and end)"
-            );
-        }
         self.logarithmic = logarithmic;
         self
     }
 
     /// Allow to drag the lower value to the right of the upper value, and vice versa.
+    /// Shorthand for [`Self::collision_policy`] with [`CollisionPolicy::Push`] or [`CollisionPolicy::Block`].
     /// Default is true.
     #[inline]
     pub fn push_by_dragging(mut self, push_by_dragging: bool) -> Self {
-        self.push_by_dragging = push_by_dragging;
+        self.collision_policy = if push_by_dragging {
+            CollisionPolicy::Push
+        } else {
+            CollisionPolicy::Block
+        };
         self
     }
 
-    fn val_to_slider_pos(&self, val: T) -> f32 {
-        let offset = self.control_point_radius + OFFSET;
-        // Calculate usable visual width of the slider track, ensuring it's not negative
-        let visual_slider_width = (self.slider_px_size - 2.0 * offset).max(0.0);
+    /// Set what happens when a dragged control point runs into the other one.
+    /// Default is [`CollisionPolicy::Push`].
+    #[inline]
+    pub fn collision_policy(mut self, collision_policy: CollisionPolicy) -> Self {
+        self.collision_policy = collision_policy;
+        self
+    }
 
-        let mut current_val_f64 = val.to_f64();
-        let mut range_min_f64;
-        let mut range_max_f64;
-        match self.orientation {
-            SliderOrientation::Horizontal => {
-                range_min_f64 = self.range.start().to_f64();
-                range_max_f64 = self.range.end().to_f64();
-            }
+    /// Set the modifier that enables precise dragging, like in [`egui::DragValue`].
+    /// While it is held, the pointer movement is scaled by [`Self::precision_factor`] when dragging a control point or the in-between part.
+    /// Default is [`Modifiers::SHIFT`], use [`Modifiers::NONE`] to disable precise dragging.
+    #[inline]
+    pub fn precision_modifier(mut self, precision_modifier: Modifiers) -> Self {
+        self.precision_modifier = precision_modifier;
+        self
+    }
+
+    /// Set the factor that the pointer movement is multiplied with while precise dragging is active.
+    /// Default is 0.1
+    #[inline]
+    pub fn precision_factor(mut self, precision_factor: f32) -> Self {
+        self.precision_factor = precision_factor;
+        self
+    }
+
+    /// Reset the values on double click.
+    /// Double clicking a control point resets its value, double clicking the in-between part or the line resets both values.
+    /// Default is disabled.
+    #[inline]
+    pub fn reset_on_double_click(mut self, default_low: T, default_high: T) -> Self {
+        self.reset_values = Some((default_low, default_high));
+        self
+    }
+
+    /// Show a context menu on right click, with actions to reset the values (if [`Self::reset_on_double_click`] is set),
+    /// select the full range, copy the selection, paste a selection (if [`Self::clipboard`] is enabled), enter exact values and toggle the logarithmic scale.
+    /// Default is false.
+    #[inline]
+    pub fn context_menu(mut self, context_menu: bool) -> Self {
+        self.context_menu = context_menu;
+        self
+    }
+
+    /// Add custom entries at the end of the context menu. This also enables the context menu.
+    #[inline]
+    pub fn context_menu_entries(mut self, add_contents: impl FnOnce(&mut Ui) + 'a) -> Self {
+        self.context_menu = true;
+        self.context_menu_entries = Some(Box::new(add_contents));
+        self
+    }
+
+    /// Copy and paste the selection with `Ctrl+C` and `Ctrl+V` while the slider has keyboard focus.
+    /// The selection is copied as `low..=high`, pasting accepts `low..=high` and `[low, high]`.
+    /// Pasted selections outside the range, closer than the separation distance, wider than the maximum span
+    /// or with a different span than a locked one (see [`Self::lock_span`]) are ignored.
+    /// Default is true.
+    #[inline]
+    pub fn clipboard(mut self, clipboard: bool) -> Self {
+        self.clipboard = clipboard;
+        self
+    }
+
+    /// Keep a history of the selections, which can be undone with `Ctrl+Z` and redone with `Ctrl+Shift+Z`
+    /// while the slider has keyboard focus. See [`SliderHistory`] to navigate it from the application.
+    /// Default is false.
+    #[inline]
+    pub fn history(mut self, history: bool) -> Self {
+        self.history = history;
+        self
+    }
 
-            SliderOrientation::Vertical => {
-                range_min_f64 = self.range.end().to_f64();
-                range_max_f64 = self.range.start().to_f64();
+    /// Add a named selection, e.g. "Last hour", which is set by clicking on it next to the slider (see [`Self::preset_display`]).
+    /// Can be called multiple times, the presets are shown in the order they were added.
+    /// A locked span (see [`Self::lock_span`]) moves the window to the start of the preset, the maximum span
+    /// (see [`Self::max_span`]) shortens it.
+    /// Default is no presets.
+    #[inline]
+    pub fn preset(mut self, name: impl Into<String>, range: RangeInclusive<T>) -> Self {
+        self.presets
+            .push((name.into(), *range.start(), *range.end()));
+        self
+    }
+
+    /// Set how the presets are shown.
+    /// Default is [`PresetDisplay::Chips`].
+    #[inline]
+    pub fn preset_display(mut self, display: PresetDisplay) -> Self {
+        self.preset_display = display;
+        self
+    }
+
+    /// Mark the ends of each preset on the line.
+    /// Default is true.
+    #[inline]
+    pub fn preset_markers(mut self, preset_markers: bool) -> Self {
+        self.preset_markers = preset_markers;
+        self
+    }
+
+    /// Move the values smoothly to a selected preset, taking the animation time of the [`egui::Style`].
+    /// Dragging or scrolling the slider stops the transition.
+    /// Default is false.
+    #[inline]
+    pub fn animate_presets(mut self, animate_presets: bool) -> Self {
+        self.animate_presets = animate_presets;
+        self
+    }
+
+    /// Add a marker of a reference value, e.g. the current playback position, drawn across the slider with a label and a tooltip.
+    /// Can be called multiple times.
+    /// Default is no markers.
+    #[inline]
+    pub fn marker(mut self, marker: Marker<T>) -> Self {
+        self.markers.push(marker);
+        self
+    }
+
+    /// Snap a dragged control point to the value of a marker while it is within `distance` points of it.
+    /// Default is no snapping.
+    #[inline]
+    pub fn snap_to_markers(mut self, distance: f32) -> Self {
+        self.marker_snap_distance = Some(distance);
+        self
+    }
+
+    /// Check the configuration of the slider.
+    /// A slider with an invalid configuration is shown in an error state, with the error as hover text.
+    pub fn validate(&self) -> Result<(), DoubleSliderError> {
+        let (start, end) = (self.range.start().to_f64(), self.range.end().to_f64());
+        if !start.is_finite() || !end.is_finite() {
+            return Err(DoubleSliderError::NonFiniteRange { start, end });
+        }
+        if self.logarithmic && !self.logarithmic_allowed() {
+            return Err(DoubleSliderError::LogarithmicRange { start, end });
+        }
+        let separation = self.separation_distance_f64();
+        if separation.is_nan() || separation < 0.0 {
+            return Err(DoubleSliderError::InvalidSeparation(separation));
+        }
+        if let Some(max_span) = self.max_span.map(|max_span| max_span.to_f64()) {
+            if max_span.is_nan() || max_span < 0.0 {
+                return Err(DoubleSliderError::InvalidMaxSpan(max_span));
+            }
+            if separation > max_span {
+                return Err(DoubleSliderError::SeparationExceedsMaxSpan {
+                    separation,
+                    max_span,
+                });
             }
         }
-
-        if self.logarithmic {
-            // Values are asserted to be > 0 in the logarithmic() setter.
-            // If range_min_f64 or range_max_f64 were <=0, log10 would produce NaN or Inf.
-            // current_val_f64 should also be > 0.
-            if current_val_f64 <= 0.0 || range_min_f64 <= 0.0 || range_max_f64 <= 0.0 {
-                // This case should ideally be prevented by assertions or clamping
-                // For safety, if inputs are invalid for log, default to a non-NaN behavior
-                // though this indicates a deeper issue if reached.
-                // Given the problem context (1..=1), this branch isn't the primary issue.
-                return offset; // Fallback to avoid NaN from log
+        for size in [self.size, self.min_size].into_iter().flatten() {
+            if !size.is_finite() || size <= 0.0 {
+                return Err(DoubleSliderError::InvalidSize(size));
+            }
+        }
+        if self.significant_digits == Some(0) {
+            return Err(DoubleSliderError::InvalidSignificantDigits);
+        }
+        let radius = self.control_point_radius;
+        if !radius.is_finite() || radius < 0.0 {
+            return Err(DoubleSliderError::InvalidControlPointRadius(radius));
+        }
+        if let Some(size) = self.size {
+            if 2.0 * radius > size {
+                return Err(DoubleSliderError::ControlPointTooLarge { radius, size });
             }
-            current_val_f64 = current_val_f64.log10();
-            range_min_f64 = range_min_f64.log10();
-            range_max_f64 = range_max_f64.log10();
         }
+        Ok(())
+    }
 
-        let range_span_f64 = range_max_f64 - range_min_f64;
+    /// The slider if its configuration is valid, see [`Self::validate`].
+    pub fn try_build(self) -> Result<Self, DoubleSliderError> {
+        self.validate().map(|()| self)
+    }
 
-        let ratio = if range_span_f64 == 0.0 {
-            // If the range is a single point (e.g., 1..=1, or log(1)..=log(1)),
-            // the value is conceptually at that point.
-            // Map this to the start (0.0) of the visual slider part.
-            0.0
-        } else {
-            // Normalize current_val_f64 to a [0, 1] ratio within the range.
-            let normalized_val = (current_val_f64 - range_min_f64) / range_span_f64;
-            normalized_val.clamp(0.0, 1.0) // Clamp to handle potential floating point inaccuracies.
+    // The mapping of values to slider positions, which are relative to `origin`, the top left corner of the slider area
+    fn mapping(&self, origin: Pos2) -> SliderMapping {
+        let offset = self.control_point_radius + OFFSET;
+        // the usable size of the slider track must not be negative
+        let positions = offset..=(self.slider_px_size - offset).max(offset);
+        let rect = match self.orientation {
+            SliderOrientation::Horizontal => Rect::from_x_y_ranges(positions, offset..=offset),
+            SliderOrientation::Vertical => Rect::from_x_y_ranges(offset..=offset, positions),
         };
+        SliderMapping::new(
+            self.range_f64(),
+            rect.translate(origin.to_vec2()),
+            self.orientation,
+        )
+        .logarithmic(self.logarithmic)
+    }
 
-        // Map the ratio to the screen coordinate.
-        (ratio as f32 * visual_slider_width) + offset
+    fn val_to_slider_pos(&self, val: T) -> f32 {
+        self.mapping(Pos2::ZERO).position_from_value(val.to_f64())
     }
 
     fn slider_pos_to_val(&self, val_along_slider: f32) -> T {
-        let offset = self.control_point_radius + OFFSET;
-        // Calculate usable visual size of the slider track, ensuring it's not negative
-        let visual_slider_size = (self.slider_px_size - 2.0 * offset).max(0.0) as f64;
+        self.normalized_to_val(
+            self.mapping(Pos2::ZERO)
+                .normalized_from_position(val_along_slider),
+        )
+    }
+
+    // The value at a normalized position, hitting the ends of the range exactly (and every value of large integers on a linear scale)
+    fn normalized_to_val(&self, normalized: f64) -> T {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        if !self.logarithmic {
+            value::lerp(start, end, normalized)
+        } else if normalized <= 0.0 {
+            start
+        } else if normalized >= 1.0 {
+            end
+        } else {
+            self.f64_to_val(self.mapping(Pos2::ZERO).value_from_normalized(normalized))
+        }
+    }
+
+    // Screen positions of the control points along the slider, the one nearer to the start of the range first
+    fn positions_along_range(&self, origin: f32) -> (f32, f32) {
+        let first_pos = origin + self.val_to_slider_pos(*self.first_slider);
+        let second_pos = origin + self.val_to_slider_pos(*self.second_slider);
+        if self.reversed() {
+            (second_pos, first_pos)
+        } else {
+            (first_pos, second_pos)
+        }
+    }
+
+    // The label of a value, with as many digits as can be set by dragging at its position
+    fn value_label(&self, value: T) -> String {
+        let pos = self.val_to_slider_pos(value);
+        let value_f64 = value.to_f64();
+        let mapping = self.mapping(Pos2::ZERO);
+        let resolution = (mapping.value_from_position(pos + 1.0) - value_f64)
+            .abs()
+            .max((value_f64 - mapping.value_from_position(pos - 1.0)).abs());
+        range_text::format_label(value, resolution, self.label_decimals(value))
+    }
 
-        let range_min_f64 = self.range.start().to_f64();
-        let range_max_f64 = self.range.end().to_f64();
+    // The values and labels of the ticks, with as many digits as needed to tell neighbouring ticks apart
+    fn tick_labels(&self) -> Vec<(T, String)> {
+        let intervals = self.ticks.saturating_sub(1).max(1);
+        let values: Vec<T> = (0..self.ticks)
+            .map(|i| self.normalized_to_val(i as f64 / intervals as f64))
+            .collect();
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| {
+                let resolution = [i.checked_sub(1), Some(i + 1)]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|neighbour| values.get(neighbour))
+                    .map(|&neighbour| value::difference(value, neighbour).abs())
+                    .fold(f64::INFINITY, f64::min);
+                let decimals = self.label_decimals(value);
+                (value, range_text::format_label(value, resolution, decimals))
+            })
+            .collect()
+    }
 
-        let value_f64 = if range_min_f64 == range_max_f64 {
-            // If the range is a single point, any x position maps to this single value.
-            range_min_f64
+    // Drag delta along the slider axis, scaled down while the precision modifier is held
+    fn drag_delta_along_slider(&self, ui: &Ui, response: &Response) -> f32 {
+        // the movement below the drag threshold is part of the first delta
+        let delta = if response.drag_started() {
+            ui.input(|i| {
+                i.pointer
+                    .press_origin()
+                    .zip(i.pointer.interact_pos())
+                    .map_or(Vec2::ZERO, |(origin, pos)| pos - origin)
+            })
         } else {
-            // Position of x relative to the start of the slider track
-            let val_on_track = (val_along_slider - offset) as f64;
+            response.drag_delta()
+        };
+        let delta = match self.orientation {
+            SliderOrientation::Horizontal => delta.x,
+            SliderOrientation::Vertical => delta.y,
+        };
+        let precise = !self.precision_modifier.is_none()
+            && ui.input(|i| i.modifiers.contains(self.precision_modifier));
+        if precise {
+            delta * self.precision_factor
+        } else {
+            delta
+        }
+    }
 
-            let mut ratio = if visual_slider_size == 0.0 {
-                // If visual width is zero (e.g. self.width is too small),
-                // effectively all points map to the start of the range.
-                0.0
+    // Accumulates the drag deltas of `response` onto the slider positions at the start of the drag.
+    // The unclamped positions are kept in temporary memory, so that sub-pixel movements
+    // (and movements beyond the edges) are not lost to rounding and clamping of the values.
+    fn drag_slider_pos(&self, ui: &Ui, response: &Response, start: (f32, f32)) -> (f32, f32) {
+        let delta = self.drag_delta_along_slider(ui, response);
+        let (first, second) = if response.drag_started() {
+            start
+        } else {
+            ui.data(|data| data.get_temp(response.id)).unwrap_or(start)
+        };
+        let pos = (first + delta, second + delta);
+        ui.data_mut(|data| data.insert_temp(response.id, pos));
+        pos
+    }
+
+    // Resets the selected values to the defaults of `reset_on_double_click` (if enabled)
+    fn reset_values(&mut self, first: bool, second: bool, response: &mut Response) {
+        if let Some((default_low, default_high)) = self.reset_values {
+            let low = if first {
+                default_low
+            } else {
+                *self.first_slider
+            };
+            let high = if second {
+                default_high
+            } else {
+                *self.second_slider
+            };
+            self.set_values(low, high, first);
+            response.mark_changed();
+        }
+    }
+
+    // Sets both values. A locked span is kept by moving the window instead,
+    // so that it starts at `first` if that was set (`first_set`) or ends at `second` otherwise.
+    fn set_values(&mut self, first: T, second: T, first_set: bool) {
+        if self.lock_span {
+            let span = value::difference(*self.first_slider, *self.second_slider);
+            let start = if first_set {
+                first
             } else {
-                (val_on_track / visual_slider_size).clamp(0.0, 1.0)
+                value::offset(second, -span)
             };
+            self.move_window(start, span);
+        } else {
+            *self.first_slider = first;
+            *self.second_slider = second;
+        }
+    }
 
-            match self.orientation {
-                SliderOrientation::Horizontal => {}
-                SliderOrientation::Vertical => ratio = 1.0 - ratio,
+    // Keeps the distance between the values within the separation distance and the maximum span after one
+    // of them was moved, according to the collision policy. Afterwards both values are clamped to the range.
+    fn resolve_collision(&mut self, first_moved: bool) {
+        let span = value::difference(*self.first_slider, *self.second_slider);
+        let max_span = self
+            .max_span
+            .map_or(f64::INFINITY, |max_span| max_span.to_f64());
+        let allowed_span = if span < self.separation_distance_f64() {
+            Some(self.separation_distance_f64())
+        } else if span > max_span {
+            Some(max_span)
+        } else {
+            None
+        };
+        if let Some(span) = allowed_span {
+            let push = self.collision_policy == CollisionPolicy::Push;
+            if first_moved == push {
+                *self.second_slider = value::offset(*self.first_slider, span);
+            } else {
+                *self.first_slider = value::offset(*self.second_slider, -span);
             }
+        }
+        *self.first_slider = self.clamp_to_range(self.first_slider);
+        *self.second_slider = self.clamp_to_range(self.second_slider);
+    }
 
-            if self.logarithmic {
-                // Values are asserted to be > 0 in the logarithmic() setter.
-                if range_min_f64 <= 0.0 || range_max_f64 <= 0.0 {
-                    // Fallback, though assertions should prevent this.
-                    return self.f64_to_val(self.range.start().to_f64());
-                }
-                let log_min = range_min_f64.log10();
-                let log_max = range_max_f64.log10();
-                // This path is taken only if range_min_f64 != range_max_f64.
-                // If they are different and positive, their logs will also be different.
-                10.0f64.powf(log_min + (log_max - log_min) * ratio)
+    // Restores the invariants of the values after any change, see the docs of `DoubleSlider`.
+    // Returns whether the values were changed.
+    fn correct_values(&mut self) -> bool {
+        let (low, high) = self.range_bounds();
+        if low.to_f64().is_nan() || high.to_f64().is_nan() {
+            return false;
+        }
+        let valid = |value: T, fallback: T| {
+            if value.to_f64().is_nan() {
+                fallback
             } else {
-                range_min_f64 + (range_max_f64 - range_min_f64) * ratio
+                self.clamp_to_range(&value)
             }
         };
+        let mut first = valid(*self.first_slider, low);
+        let mut second = valid(*self.second_slider, high);
+        if first > second {
+            std::mem::swap(&mut first, &mut second);
+        }
+
+        // a separation that does not fit into the range keeps the values at its ends
+        let separation = self.separation_distance_f64();
+        let separation = if separation.is_nan() { 0.0 } else { separation }
+            .clamp(0.0, value::difference(low, high));
+        let gap = value::difference(first, second);
+        if gap < separation {
+            // move both values apart from their center, as far as the range allows
+            first = value::offset(first, (gap - separation) / 2.0);
+            let last = value::offset(high, -separation);
+            if first > last {
+                first = last;
+            }
+            if first < low {
+                first = low;
+            }
+            second = self.clamp_to_range(&value::offset(first, separation));
+        }
+
+        // a span larger than the maximum is shrunk around its center
+        if let Some(max_span) = self.max_span.map(|max_span| max_span.to_f64()) {
+            let gap = value::difference(first, second);
+            if gap > max_span {
+                first = value::offset(first, (gap - max_span) / 2.0);
+                second = value::offset(first, max_span);
+            }
+        }
 
-        self.f64_to_val(value_f64)
+        let changed = first != *self.first_slider || second != *self.second_slider;
+        *self.first_slider = first;
+        *self.second_slider = second;
+        changed
     }
 
-    fn first_slider_f64(&self) -> f64 {
-        self.first_slider.to_f64()
+    // Swaps the values if the dragged one was moved past the other one and the collision policy allows it
+    fn swap_crossed(&mut self) -> bool {
+        let crossed = *self.first_slider > *self.second_slider;
+        if crossed && self.collision_policy == CollisionPolicy::Swap {
+            std::mem::swap(self.first_slider, self.second_slider);
+            true
+        } else {
+            false
+        }
     }
 
-    fn second_slider_f64(&self) -> f64 {
-        self.second_slider.to_f64()
+    // Moves the window of a locked span to start at `first`, keeping it inside the range
+    fn move_window(&mut self, first: T, span: f64) {
+        let (low, high) = self.range_bounds();
+        let last = value::offset(high, -span);
+        let first = if first > last { last } else { first };
+        let first = if first < low { low } else { first };
+        *self.first_slider = first;
+        *self.second_slider = self.clamp_to_range(&value::offset(first, span));
+    }
+
+    fn logarithmic_allowed(&self) -> bool {
+        let range_f64 = self.range_f64();
+        *range_f64.start() > 0.0
+            && range_f64.start().is_finite()
+            && *range_f64.end() > 0.0
+            && range_f64.end().is_finite()
+    }
+
+    fn show_context_menu(&mut self, response: &mut Response, logarithmic_id: Id) {
+        let slider_id = response.id;
+        let menu = Popup::context_menu(response)
+            .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+            .show(|ui| {
+                // (any value changed, lower value changed)
+                let mut changed = (false, false);
+
+                if let Some((default_low, default_high)) = self.reset_values {
+                    if ui.button("Reset").clicked() {
+                        self.set_values(default_low, default_high, true);
+                        changed = (true, true);
+                        ui.close();
+                    }
+                }
+                // a locked span cannot cover the full range
+                if !self.lock_span && ui.button("Select full range").clicked() {
+                    (*self.first_slider, *self.second_slider) = self.range_bounds();
+                    changed = (true, true);
+                    ui.close();
+                }
+                if ui.button("Copy range").clicked() {
+                    ui.ctx().copy_text(range_text::format_range(
+                        *self.first_slider,
+                        *self.second_slider,
+                    ));
+                    ui.close();
+                }
+                // the pasted text arrives as an event in the next frame, see `Self::clipboard`
+                if self.clipboard && ui.button("Paste range").clicked() {
+                    ui.memory_mut(|memory| memory.request_focus(slider_id));
+                    ui.ctx().send_viewport_cmd(ViewportCommand::RequestPaste);
+                    ui.close();
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label("Lower:");
+                    let mut low = *self.first_slider;
+                    if ui
+                        .add(
+                            DragValue::new(&mut low)
+                                .range(self.range.clone())
+                                .min_decimals(self.min_decimals)
+                                .max_decimals_opt(self.max_decimals),
+                        )
+                        .changed()
+                    {
+                        self.set_values(low, *self.second_slider, true);
+                        changed = (true, true);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Upper:");
+                    let mut high = *self.second_slider;
+                    if ui
+                        .add(
+                            DragValue::new(&mut high)
+                                .range(self.range.clone())
+                                .min_decimals(self.min_decimals)
+                                .max_decimals_opt(self.max_decimals),
+                        )
+                        .changed()
+                    {
+                        self.set_values(*self.first_slider, high, false);
+                        changed = (true, false);
+                    }
+                });
+
+                if self.logarithmic_allowed() {
+                    let mut logarithmic = self.logarithmic;
+                    if ui.checkbox(&mut logarithmic, "Logarithmic").changed() {
+                        ui.data_mut(|data| data.insert_temp(logarithmic_id, logarithmic));
+                    }
+                }
+
+                if let Some(add_contents) = self.context_menu_entries.take() {
+                    ui.separator();
+                    add_contents(ui);
+                }
+
+                changed
+            });
+
+        if let Some((true, first_moved)) = menu.map(|menu| menu.inner) {
+            self.resolve_collision(first_moved);
+            response.mark_changed();
+        }
+    }
+
+    // Sets the values to the current selection of the history, if it was moved to
+    fn apply_history(&mut self, history: &mut SliderHistory, response: &mut Response) {
+        if let Some((low, high)) = history.take_pending() {
+            *self.first_slider = low;
+            *self.second_slider = high;
+            response.mark_changed();
+        }
+    }
+
+    // The markers inside the range
+    fn visible_markers(&self) -> impl Iterator<Item = &Marker<T>> {
+        self.markers
+            .iter()
+            .filter(|marker| self.clamp_to_range(&marker.value) == marker.value)
+    }
+
+    // The value of the nearest marker within the snapping distance of `pos` along the slider, otherwise `value`
+    fn snap_to_marker(&self, pos: f32, value: T) -> T {
+        let Some(snap_distance) = self.marker_snap_distance else {
+            return value;
+        };
+        self.visible_markers()
+            .map(|marker| {
+                (
+                    marker.value,
+                    (self.val_to_slider_pos(marker.value) - pos).abs(),
+                )
+            })
+            .filter(|&(_, distance)| distance <= snap_distance)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map_or(value, |(marker, _)| marker)
+    }
+
+    // Moves the values along a running transition to a preset. Returns whether it is still running.
+    fn animate_preset(&mut self, ui: &Ui, response: &mut Response) -> bool {
+        let Some(animation) = PresetAnimation::load(ui.ctx(), response.id) else {
+            return false;
+        };
+        let Some(&(_, low, high)) = self.presets.get(animation.preset) else {
+            PresetAnimation::clear(ui.ctx(), response.id);
+            return false;
+        };
+        let t = animation.progress(ui.input(|i| i.time), ui.style().animation_time);
+        if t < 1.0 {
+            let (from_low, from_high) = animation.from;
+            self.set_values(
+                value::lerp(T::from_f64(from_low), low, t),
+                value::lerp(T::from_f64(from_high), high, t),
+                true,
+            );
+            ui.ctx().request_repaint();
+        } else {
+            // the preset is hit exactly at the end
+            self.set_values(low, high, true);
+            PresetAnimation::clear(ui.ctx(), response.id);
+        }
+        self.resolve_collision(true);
+        response.mark_changed();
+        t < 1.0
+    }
+
+    // Shows the presets as chips or a dropdown, returns the index of the one that was clicked
+    fn show_presets(&self, ui: &mut Ui, slider_id: Id) -> Option<usize> {
+        let selected = self
+            .presets
+            .iter()
+            .position(|&(_, low, high)| (low, high) == (*self.first_slider, *self.second_slider));
+        let mut clicked = None;
+        ui.add_enabled_ui(!self.read_only, |ui| match self.preset_display {
+            PresetDisplay::Chips => {
+                let show_chips = |ui: &mut Ui| {
+                    for (index, (name, low, high)) in self.presets.iter().enumerate() {
+                        let chip = ui
+                            .selectable_label(selected == Some(index), name.as_str())
+                            .on_hover_text(range_text::format_range(*low, *high));
+                        if chip.clicked() {
+                            clicked = Some(index);
+                        }
+                    }
+                };
+                if self.orientation == SliderOrientation::Horizontal {
+                    ui.horizontal_wrapped(show_chips);
+                } else {
+                    ui.vertical(show_chips);
+                }
+            }
+            PresetDisplay::Dropdown => {
+                let selected_text =
+                    selected.map_or("Custom", |index| self.presets[index].0.as_str());
+                ComboBox::from_id_salt(slider_id.with("presets"))
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui| {
+                        for (index, (name, _, _)) in self.presets.iter().enumerate() {
+                            if ui
+                                .selectable_label(selected == Some(index), name.as_str())
+                                .clicked()
+                            {
+                                clicked = Some(index);
+                            }
+                        }
+                    });
+            }
+        });
+        clicked
+    }
+
+    // Sets the values to a preset, or starts the transition to it
+    fn select_preset(&mut self, ui: &Ui, index: usize, response: &mut Response) {
+        if self.history {
+            SliderHistory::mark_interaction(ui.ctx(), response.id);
+        }
+        if self.animate_presets {
+            PresetAnimation {
+                preset: index,
+                from: (self.first_slider.to_f64(), self.second_slider.to_f64()),
+                start_time: ui.input(|i| i.time),
+            }
+            .store(ui.ctx(), response.id);
+        } else {
+            let (_, low, high) = self.presets[index];
+            self.set_values(low, high, true);
+            self.resolve_collision(true);
+            self.correct_values();
+            response.mark_changed();
+        }
+        // the slider was already drawn with the previous values
+        ui.ctx().request_repaint();
+    }
+
+    // Sets both values from a pasted selection, returns false if it is invalid for this slider
+    fn paste_range(&mut self, text: &str) -> bool {
+        let Some((low, high)) = range_text::parse_range(text) else {
+            return false;
+        };
+        let (range_low, range_high) = self.range_bounds();
+        let span = value::difference(low, high);
+        let max_span = self
+            .max_span
+            .map_or(f64::INFINITY, |max_span| max_span.to_f64());
+        let locked_span = self
+            .lock_span
+            .then(|| value::difference(*self.first_slider, *self.second_slider));
+        if low < range_low
+            || high > range_high
+            || span < self.separation_distance_f64()
+            || span > max_span
+            || locked_span.is_some_and(|locked_span| span != locked_span)
+        {
+            return false;
+        }
+        *self.first_slider = low;
+        *self.second_slider = high;
+        true
+    }
+
+    // The primary size of the slider, filling the available space if no size is set
+    fn slider_size(&self, ui: &Ui) -> f32 {
+        // along the layout, the space is only filled if it is justified, so that later widgets still fit
+        let layout = ui.layout();
+        let (available, fill) = match self.orientation {
+            SliderOrientation::Horizontal => (
+                ui.available_size_before_wrap().x,
+                layout.is_vertical() || layout.horizontal_justify(),
+            ),
+            SliderOrientation::Vertical => (
+                ui.available_size_before_wrap().y,
+                layout.is_horizontal() || layout.vertical_justify(),
+            ),
+        };
+        let size = self
+            .size
+            .unwrap_or(if fill && !ui.is_sizing_pass() && available.is_finite() {
+                available
+            } else {
+                ui.spacing().slider_width
+            });
+        let min_size = self
+            .min_size
+            .unwrap_or(ui.spacing().interact_size.x)
+            .max(2.0 * (self.control_point_radius + OFFSET));
+        size.max(min_size)
+    }
+
+    // Shows an invalid slider as a warning sign in the space of a default slider, with the error as hover text
+    fn show_error(&self, ui: &mut Ui, error: &DoubleSliderError) -> Response {
+        let along = ui.spacing().slider_width;
+        let across = ui.spacing().interact_size.y;
+        let size = match self.orientation {
+            SliderOrientation::Horizontal => Vec2::new(along, across),
+            SliderOrientation::Vertical => Vec2::new(across, along),
+        };
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        if ui.is_rect_visible(rect) {
+            let color = ui.visuals().error_fg_color;
+            let painter = ui.painter();
+            painter.rect_stroke(
+                rect,
+                ui.visuals().widgets.inactive.corner_radius,
+                Stroke::new(1.0, color),
+                StrokeKind::Inside,
+            );
+            painter.text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                "⚠",
+                TextStyle::Body.resolve(ui.style()),
+                color,
+            );
+        }
+        response.on_hover_text(error.to_string())
     }
 
     fn separation_distance_f64(&self) -> f64 {
@@ -312,402 +1241,755 @@ impl<'a, T: Numeric> DoubleSlider<'a, T> {
         self.range.start().to_f64()..=self.range.end().to_f64()
     }
 
+    // The range goes from high to low
+    fn reversed(&self) -> bool {
+        self.range.start() > self.range.end()
+    }
+
+    // The lowest and the highest value of the range, in any direction
+    fn range_bounds(&self) -> (T, T) {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        if start > end {
+            (end, start)
+        } else {
+            (start, end)
+        }
+    }
+
     // Rounds decimal values when casting to integers (instead of truncating like a native float-to-int cast)
     fn f64_to_val(&self, float: f64) -> T {
         T::from_f64(if T::INTEGRAL { float.round() } else { float })
     }
 
+    // Values are rounded when they are changed by interactions, see `max_decimals` and `significant_digits`
+    fn rounds_values(&self) -> bool {
+        !T::INTEGRAL && (self.max_decimals.is_some() || self.significant_digits.is_some())
+    }
+
+    // The value rounded to the maximum decimals and significant digits, which may be outside of the range
+    fn round_value(&self, value: T) -> T {
+        if !self.rounds_values() {
+            return value;
+        }
+        let float = value.to_f64();
+        let decimals = [
+            self.max_decimals.map(|decimals| decimals as i32),
+            self.significant_digits
+                .map(|digits| value::significant_decimals(float, digits)),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(i32::MAX);
+        T::from_f64(value::round_to_decimals(float, decimals))
+    }
+
+    // Rounds both values after they were changed together, keeping them inside the range
+    fn round_values(&mut self) {
+        *self.first_slider = self.clamp_to_range(&self.round_value(*self.first_slider));
+        *self.second_slider = self.clamp_to_range(&self.round_value(*self.second_slider));
+    }
+
+    // The range of decimals shown in the label of a value
+    fn label_decimals(&self, value: T) -> RangeInclusive<usize> {
+        let max_decimals = [
+            self.max_decimals,
+            self.significant_digits
+                .map(|digits| value::significant_decimals(value.to_f64(), digits).max(0) as usize),
+        ]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(usize::MAX);
+        self.min_decimals..=max_decimals.max(self.min_decimals)
+    }
+
     fn clamp_to_range(&self, val: &T) -> T {
-        let (start, end) = (self.range.start().to_f64(), self.range.end().to_f64());
-        self.f64_to_val(val.to_f64().clamp(start, end))
+        let (low, high) = self.range_bounds();
+        if *val < low {
+            low
+        } else if *val > high {
+            high
+        } else {
+            *val
+        }
     }
 }
 
-impl<'a, T: Numeric> Widget for DoubleSlider<'a, T> {
-    fn ui(self, ui: &mut Ui) -> egui::Response {
-        // calculate height
-        let accros_slider_size = 2.0 * self.control_point_radius + 2.0 * OFFSET;
+impl<'a, T: Numeric> DoubleSlider<'a, T> {
+    // The same slider, showing and changing `first` and `second` instead of the values of the application
+    fn detached<'b>(self, first: &'b mut T, second: &'b mut T) -> DoubleSlider<'b, T>
+    where
+        'a: 'b,
+    {
+        DoubleSlider {
+            first_slider: first,
+            second_slider: second,
+            ..self
+        }
+    }
 
-        let x_size;
-        let y_size;
-        match self.orientation {
-            SliderOrientation::Horizontal => {
-                x_size = self.slider_px_size;
-                y_size = accros_slider_size;
+    // Shows the slider with the presets next to it
+    fn add_to(mut self, ui: &mut Ui) -> Response {
+        if self.presets.is_empty() || self.validate().is_err() {
+            return self.show(ui);
+        }
+        // the presets are attached below a horizontal and to the right of a vertical slider
+        let orientation = self.orientation;
+        let show = |ui: &mut Ui| {
+            let mut response = self.show(ui);
+            if let Some(index) = self.show_presets(ui, response.id) {
+                self.select_preset(ui, index, &mut response);
             }
-            SliderOrientation::Vertical => {
-                x_size = accros_slider_size;
-                y_size = self.slider_px_size;
+            response
+        };
+        match orientation {
+            SliderOrientation::Horizontal => ui.vertical(show).inner,
+            SliderOrientation::Vertical => ui.horizontal(show).inner,
+        }
+    }
+
+    // Shows the slider with everything drawn around it, but without the presets
+    fn show(&mut self, ui: &mut Ui) -> Response {
+        if let Err(error) = self.validate() {
+            return self.show_error(ui, &error);
+        }
+
+        // the logarithmic scale can be toggled in the context menu, before the labels are laid out.
+        // The slider is the next widget, so its id is known before it is allocated.
+        let logarithmic_id = ui.next_auto_id().with("logarithmic");
+        if self.context_menu && self.logarithmic_allowed() {
+            if let Some(logarithmic) = ui.data(|data| data.get_temp(logarithmic_id)) {
+                self.logarithmic = logarithmic;
             }
         }
 
+        // the size is recomputed every frame, so that the slider follows the available space
+        self.slider_px_size = self.slider_size(ui);
+
+        // values set by the application are corrected before they are laid out
+        let corrected = self.correct_values();
+
+        let mut style = self
+            .style
+            .or_else(|| DoubleSliderStyle::global(ui.ctx()))
+            .unwrap_or_else(|| DoubleSliderStyle::from_visuals(ui.visuals()));
+        if let Some(color) = self.color {
+            style.track.set_fill(color);
+        }
+        if let Some(stroke) = self.stroke {
+            style.band.set_fill(stroke.color);
+        }
+        if let Some(cursor_fill) = self.cursor_fill {
+            style.lower_handle.set_fill(cursor_fill);
+            style.upper_handle.set_fill(cursor_fill);
+        }
+        let thickness = self
+            .track_thickness
+            .or(self.stroke.map(|stroke| stroke.width))
+            .unwrap_or(ui.spacing().slider_rail_height);
+        let corner_radius = self
+            .track_corner_radius
+            .unwrap_or_else(|| ui.visuals().widgets.inactive.corner_radius.average());
+
+        // measure everything that is drawn around the line and the control points
+        let value_font = TextStyle::Body.resolve(ui.style());
+        let tick_font = TextStyle::Small.resolve(ui.style());
+        let label_size = |text: String, font: &FontId| {
+            ui.painter()
+                .layout_no_wrap(text, font.clone(), Color32::PLACEHOLDER)
+                .size()
+        };
+        let value_label = self.value_labels.then(|| {
+            [
+                *self.range.start(),
+                *self.range.end(),
+                *self.first_slider,
+                *self.second_slider,
+            ]
+            .map(|value| label_size(self.value_label(value), &value_font))
+            .into_iter()
+            .fold(Vec2::ZERO, Vec2::max)
+        });
+        let tick_labels = self.tick_labels();
+        let tick_label = (!tick_labels.is_empty()).then(|| {
+            tick_labels
+                .iter()
+                .map(|(_, text)| label_size(text.clone(), &tick_font))
+                .fold(Vec2::ZERO, Vec2::max)
+        });
+        let marker_label = self
+            .visible_markers()
+            .filter(|marker| !marker.label.is_empty())
+            .map(|marker| label_size(marker.label.clone(), &tick_font))
+            .reduce(Vec2::max);
+        let handle_offset = self.control_point_radius + OFFSET;
+        let handle_stroke = style
+            .lower_handle
+            .max_stroke_width()
+            .max(style.upper_handle.max_stroke_width());
+        let outline = style.track.max_stroke_width();
+        let layout = SliderLayout {
+            orientation: self.orientation,
+            handle_offset,
+            along_overhang: match self.track_cap {
+                TrackCap::Extended => outline,
+                TrackCap::Flush => 0.0,
+            },
+            across_overhang: (handle_stroke / 2.0 - OFFSET)
+                .max(thickness / 2.0 + outline - handle_offset)
+                .max(0.0),
+            value_label,
+            tick_label,
+            marker_label,
+        };
+
+        // the line and the control points get the size that is left
+        let margin = layout.margin();
+        let along_margin = match self.orientation {
+            SliderOrientation::Horizontal => margin.left + margin.right,
+            SliderOrientation::Vertical => margin.top + margin.bottom,
+        };
+        self.slider_px_size = (self.slider_px_size - along_margin).max(2.0 * handle_offset);
+
+        // calculate height
+        let accros_slider_size = 2.0 * self.control_point_radius + 2.0 * OFFSET;
+
+        let (x_size, y_size) = match self.orientation {
+            SliderOrientation::Horizontal => (self.slider_px_size, accros_slider_size),
+            SliderOrientation::Vertical => (accros_slider_size, self.slider_px_size),
+        };
+
+        // read-only and disabled sliders only react to hovering
+        let interactive = ui.is_enabled() && !self.read_only;
+        let sense = if self.read_only {
+            Sense::hover()
+        } else {
+            Sense::click_and_drag()
+        };
+
         let (mut response, painter) =
-            ui.allocate_painter(Vec2::new(x_size, y_size), Sense::click_and_drag());
+            ui.allocate_painter(layout.outer_size(Vec2::new(x_size, y_size)), sense);
+        let slider_rect = layout.slider_rect(response.rect);
+        let mapping = self.mapping(slider_rect.min);
+        mapping.store(ui.ctx(), response.id);
+
+        // a selection that the application moved to in the history replaces the values,
+        // once the slider can change them
+        let mut history = self.history.then(|| {
+            SliderHistory::load(ui.ctx(), response.id)
+                .unwrap_or_else(|| SliderHistory::new(*self.first_slider, *self.second_slider))
+        });
+        if let (Some(history), true) = (&mut history, interactive) {
+            self.apply_history(history, &mut response);
+        }
+        let animating = interactive && self.animate_preset(ui, &mut response);
+        // any change from here on is made by the user
+        let initial_values = (*self.first_slider, *self.second_slider);
 
-        let mut start_edge;
-        let mut end_edge;
-        match self.orientation {
-            SliderOrientation::Horizontal => {
-                start_edge = response.rect.left_center();
-                start_edge.x += self.control_point_radius;
-                end_edge = response.rect.right_center();
-                end_edge.x -= self.control_point_radius;
-            }
-            SliderOrientation::Vertical => {
-                start_edge = response.rect.center_bottom();
-                start_edge.y -= self.control_point_radius;
-                end_edge = response.rect.center_top();
-                end_edge.y += self.control_point_radius;
-            }
+        if response.double_clicked() {
+            self.reset_values(true, true, &mut response);
         }
 
-        let visuals = ui.style().interact(&response);
-        let widget_visuals = &ui.visuals().widgets;
+        // with a locked span, clicking on the line centers the window at the pointer
+        let locked_span = self
+            .lock_span
+            .then(|| value::difference(*self.first_slider, *self.second_slider));
+        if let (Some(span), true) = (locked_span, response.clicked()) {
+            if let Some(pointer_pos) = response.interact_pointer_pos() {
+                let center = match self.orientation {
+                    SliderOrientation::Horizontal => {
+                        self.slider_pos_to_val(pointer_pos.x - slider_rect.left())
+                    }
+                    SliderOrientation::Vertical => {
+                        self.slider_pos_to_val(pointer_pos.y - slider_rect.top())
+                    }
+                };
+                self.move_window(value::offset(center, -span / 2.0), span);
+                self.round_values();
+                response.mark_changed();
+            }
+        }
 
-        let color = self.color.unwrap_or(widget_visuals.inactive.bg_fill);
-        let cursor_fill = self.cursor_fill.unwrap_or(visuals.bg_fill);
-        let stroke_style = self
-            .stroke
-            .unwrap_or(Stroke::new(7.0, ui.visuals().selection.bg_fill));
+        let track = TrackLayout::new(
+            slider_rect,
+            self.orientation,
+            thickness,
+            corner_radius,
+            self.track_cap,
+            handle_offset,
+        );
+        // slider positions are relative to the top left corner of the slider area
+        let origin = track.along(slider_rect.min);
+
+        // the slider counts as focused if any of its parts has the focus
+        let part_ids = [
+            response.id,
+            response.id.with(0),
+            response.id.with(1),
+            response.id.with(2),
+        ];
+        let has_focus = ui.memory(|mem| mem.focused().is_some_and(|id| part_ids.contains(&id)));
 
         // draw the line
-        painter.add(PathShape::line(
-            vec![start_edge, end_edge],
-            Stroke::new(stroke_style.width, color),
-        ));
+        let track_state = InteractionState::from_response(&response).with_focus(has_focus);
+        let track_visuals = style.track.visuals(track_state);
+        if let Some(gradient) = &self.track_gradient {
+            painter.add(gradient.mesh(track.rect, track.corner_radius, &mapping));
+        } else {
+            painter.rect_filled(track.rect, track.corner_radius, track_visuals.fill);
+        }
+        if track_visuals.stroke.width > 0.0 {
+            painter.rect_stroke(
+                track.rect,
+                track.corner_radius,
+                track_visuals.stroke,
+                StrokeKind::Outside,
+            );
+        }
 
-        let to_screen = RectTransform::from_to(
-            Rect::from_min_size(Pos2::ZERO, response.rect.size()),
-            response.rect,
-        );
-        let mut shapes = vec![];
-        let stroke = if !self.inverted_highlighting {
-            let in_between_rect;
-            match self.orientation {
-                SliderOrientation::Horizontal => {
-                    in_between_rect = Rect::from_min_max(
-                        to_screen.transform_pos(Pos2 {
-                            x: self.val_to_slider_pos(*self.first_slider)
-                                + self.control_point_radius,
-                            y: accros_slider_size / 2.0 - stroke_style.width / 2.0,
-                        }),
-                        to_screen.transform_pos(Pos2 {
-                            x: self.val_to_slider_pos(*self.second_slider)
-                                - self.control_point_radius,
-                            y: accros_slider_size / 2.0 + stroke_style.width / 2.0,
-                        }),
-                    );
-                }
-                SliderOrientation::Vertical => {
-                    in_between_rect = Rect::from_min_max(
-                        to_screen.transform_pos(Pos2 {
-                            x: accros_slider_size / 2.0 - stroke_style.width / 2.0,
-                            y: self.val_to_slider_pos(*self.second_slider)
-                                + self.control_point_radius,
-                        }),
-                        to_screen.transform_pos(Pos2 {
-                            x: accros_slider_size / 2.0 + stroke_style.width / 2.0,
-                            y: self.val_to_slider_pos(*self.first_slider)
-                                - self.control_point_radius,
-                        }),
+        // draw the ticks
+        let tick_color = ui.visuals().weak_text_color();
+        for (value, text) in tick_labels {
+            let pos = mapping.position_from_value(value.to_f64());
+            let (mark, label_pos, align) = layout.tick(slider_rect, pos);
+            painter.line_segment(mark, Stroke::new(1.0, tick_color));
+            let galley = painter.layout_no_wrap(text, tick_font.clone(), tick_color);
+            let label_rect =
+                layout.keep_inside(align.anchor_size(label_pos, galley.size()), response.rect);
+            painter.galley(label_rect.min, galley, tick_color);
+        }
+
+        // mark the ends of the presets across the line
+        if self.preset_markers {
+            for &(_, low, high) in &self.presets {
+                for value in [low, high] {
+                    let pos = mapping.position_from_value(value.to_f64());
+                    painter.line_segment(
+                        track.across(pos, PRESET_MARKER_OVERHANG),
+                        Stroke::new(1.0, tick_color),
                     );
                 }
             }
+        }
+
+        let to_screen = RectTransform::from_to(
+            Rect::from_min_size(Pos2::ZERO, slider_rect.size()),
+            slider_rect,
+        );
+        let mut shapes = vec![];
+        let mut band_state = if response.enabled() {
+            InteractionState::Inactive
+        } else {
+            InteractionState::Disabled
+        };
+        if !self.inverted_highlighting || self.lock_span {
+            // with a locked span, the control points are part of the draggable window
+            let inset = if self.lock_span {
+                -self.control_point_radius
+            } else {
+                self.control_point_radius
+            };
+            // on a vertical slider, the position nearer to the end of the range is above the other one
+            let (start_pos, end_pos) = self.positions_along_range(origin);
+            let in_between_rect = match self.orientation {
+                SliderOrientation::Horizontal => Rect::from_min_max(
+                    Pos2::new(start_pos + inset, track.rect.top()),
+                    Pos2::new(end_pos - inset, track.rect.bottom()),
+                ),
+                SliderOrientation::Vertical => Rect::from_min_max(
+                    Pos2::new(track.rect.left(), end_pos + inset),
+                    Pos2::new(track.rect.right(), start_pos - inset),
+                ),
+            };
 
             let in_between_id = response.id.with(2);
-            let in_between_response =
-                ui.interact(in_between_rect, in_between_id, Sense::click_and_drag());
+            let in_between_response = ui.interact(in_between_rect, in_between_id, sense);
 
             // drag both sliders by dragging the highlighted part (only when not highlighting is not inverted)
             if in_between_response.dragged() {
-                let drag_delta = if self.orientation == SliderOrientation::Horizontal {
-                    in_between_response.drag_delta().x
+                let (first_pos, second_pos) = self.drag_slider_pos(
+                    ui,
+                    &in_between_response,
+                    (
+                        self.val_to_slider_pos(*self.first_slider),
+                        self.val_to_slider_pos(*self.second_slider),
+                    ),
+                );
+                if let Some(span) = locked_span {
+                    self.move_window(self.slider_pos_to_val(first_pos), span);
                 } else {
-                    in_between_response.drag_delta().y
-                };
-                *self.second_slider = self
-                    .slider_pos_to_val(self.val_to_slider_pos(*self.second_slider) + drag_delta);
-                *self.first_slider =
-                    self.slider_pos_to_val(self.val_to_slider_pos(*self.first_slider) + drag_delta);
+                    *self.second_slider = self.slider_pos_to_val(second_pos);
+                    *self.first_slider = self.slider_pos_to_val(first_pos);
+                }
+                self.round_values();
                 response.mark_changed();
             }
 
-            response |= in_between_response.clone();
+            if in_between_response.double_clicked() {
+                self.reset_values(true, true, &mut response);
+            }
 
-            if in_between_response.hovered() {
-                let mut stroke = ui.style().interact(&in_between_response).fg_stroke;
-                stroke.width /= 2.0;
-                stroke
-            } else {
-                Stroke::new(1.0, stroke_style.color)
+            band_state =
+                InteractionState::from_response(&in_between_response).with_focus(has_focus);
+            response |= in_between_response;
+        }
+        let band_visuals = style.band.visuals(band_state);
+        let stroke = if self.inverted_highlighting {
+            Stroke::NONE
+        } else {
+            band_visuals.stroke
+        };
+
+        // with a locked span the control points only show the ends of the window
+        let point_sense = if self.lock_span {
+            Sense::hover()
+        } else {
+            sense
+        };
+
+        // after swapping during a drag, the dragged control point keeps its id but controls the other value
+        let swapped_id = response.id.with("swapped");
+        let mut swapped = ui.data(|data| data.get_temp(swapped_id)).unwrap_or(false);
+
+        // when the control points overlap, the drag direction decides which one is grabbed
+        let overlapping = (self.val_to_slider_pos(*self.second_slider)
+            - self.val_to_slider_pos(*self.first_slider))
+        .abs()
+            < 2.0 * self.control_point_radius;
+        let point_ids = [response.id.with(0), response.id.with(1)];
+        if let Some(grabbed_id) = ui
+            .ctx()
+            .drag_started_id()
+            .filter(|id| overlapping && point_ids.contains(id))
+        {
+            let drag = ui.input(|i| {
+                i.pointer
+                    .press_origin()
+                    .zip(i.pointer.interact_pos())
+                    .map_or(Vec2::ZERO, |(origin, pos)| pos - origin)
+            });
+            // positive towards the end of the range, where the upper value is unless the range is reversed
+            let drag = match self.orientation {
+                SliderOrientation::Horizontal => drag.x,
+                SliderOrientation::Vertical => -drag.y,
+            };
+            let drag = if self.reversed() { -drag } else { drag };
+            let grabbed_first = (grabbed_id == point_ids[0]) != swapped;
+            if drag != 0.0 && grabbed_first == (drag > 0.0) {
+                swapped = !swapped;
             }
+        }
+
+        let (first_point_id, second_point_id) = if swapped {
+            (response.id.with(1), response.id.with(0))
         } else {
-            Stroke::new(0.0, stroke_style.color)
+            (response.id.with(0), response.id.with(1))
         };
 
         // handle lower bound
         // get the control point
         let size = Vec2::splat(2.0 * self.control_point_radius);
 
-        let first_point_in_screen;
-        match self.orientation {
-            SliderOrientation::Horizontal => {
-                first_point_in_screen = to_screen.transform_pos(Pos2 {
-                    x: self.val_to_slider_pos(*self.first_slider),
-                    y: self.control_point_radius + OFFSET,
-                });
-            }
-            SliderOrientation::Vertical => {
-                first_point_in_screen = to_screen.transform_pos(Pos2 {
-                    x: self.control_point_radius + OFFSET,
-                    y: self.val_to_slider_pos(*self.first_slider),
-                });
-            }
-        }
+        let first_point_in_screen = match self.orientation {
+            SliderOrientation::Horizontal => to_screen.transform_pos(Pos2 {
+                x: self.val_to_slider_pos(*self.first_slider),
+                y: self.control_point_radius + OFFSET,
+            }),
+            SliderOrientation::Vertical => to_screen.transform_pos(Pos2 {
+                x: self.control_point_radius + OFFSET,
+                y: self.val_to_slider_pos(*self.first_slider),
+            }),
+        };
 
         let point_rect = Rect::from_center_size(first_point_in_screen, size);
-        let point_id = response.id.with(0);
-        let point_response = ui.interact(point_rect, point_id, Sense::click_and_drag());
+        let point_response = ui.interact(point_rect, first_point_id, point_sense);
+        let mut first_moved = true;
 
         if point_response.dragged() {
-            if let Some(pointer_pos) = point_response.interact_pointer_pos() {
-                match self.orientation {
-                    SliderOrientation::Horizontal => {
-                        *self.first_slider =
-                            self.slider_pos_to_val(pointer_pos.x - response.rect.left());
-                    }
-                    SliderOrientation::Vertical => {
-                        *self.first_slider =
-                            self.slider_pos_to_val(pointer_pos.y - response.rect.top());
-                    }
-                }
-                response.mark_changed();
+            let pos = self.val_to_slider_pos(*self.first_slider);
+            let (pos, _) = self.drag_slider_pos(ui, &point_response, (pos, pos));
+            *self.first_slider =
+                self.snap_to_marker(pos, self.round_value(self.slider_pos_to_val(pos)));
+            if self.swap_crossed() {
+                swapped = !swapped;
+                first_moved = false;
             }
+            response.mark_changed();
         }
 
-        // handle logic
-        if self.second_slider_f64() < self.first_slider_f64() + self.separation_distance_f64() {
-            if self.push_by_dragging {
-                *self.second_slider =
-                    self.f64_to_val(self.first_slider_f64() + self.separation_distance_f64());
-            } else {
-                *self.first_slider =
-                    self.f64_to_val(self.second_slider_f64() - self.separation_distance_f64());
-            }
+        if point_response.double_clicked() {
+            self.reset_values(true, false, &mut response);
         }
-        *self.first_slider = self.clamp_to_range(self.first_slider);
-        *self.second_slider = self.clamp_to_range(self.second_slider);
 
-        let left_circle_stroke = ui.style().interact(&point_response).fg_stroke;
+        // handle logic
+        self.resolve_collision(first_moved);
+        let first_point_dragged = point_response.dragged();
+        let first_point_hovered = point_response.hovered();
+
+        let mut first_point_state =
+            InteractionState::from_response(&point_response).with_focus(has_focus);
         response |= point_response;
 
         // handle upper bound
         // get the control point
 
-        let second_point_in_screen;
-        match self.orientation {
-            SliderOrientation::Horizontal => {
-                second_point_in_screen = to_screen.transform_pos(Pos2 {
-                    x: self.val_to_slider_pos(*self.second_slider),
-                    y: self.control_point_radius + OFFSET,
-                });
-            }
-            SliderOrientation::Vertical => {
-                second_point_in_screen = to_screen.transform_pos(Pos2 {
-                    x: self.control_point_radius + OFFSET,
-                    y: self.val_to_slider_pos(*self.second_slider),
-                });
-            }
-        }
+        let second_point_in_screen = match self.orientation {
+            SliderOrientation::Horizontal => to_screen.transform_pos(Pos2 {
+                x: self.val_to_slider_pos(*self.second_slider),
+                y: self.control_point_radius + OFFSET,
+            }),
+            SliderOrientation::Vertical => to_screen.transform_pos(Pos2 {
+                x: self.control_point_radius + OFFSET,
+                y: self.val_to_slider_pos(*self.second_slider),
+            }),
+        };
 
         let point_rect = Rect::from_center_size(second_point_in_screen, size);
-        let point_id = response.id.with(1);
-        let point_response = ui.interact(point_rect, point_id, Sense::click_and_drag());
+        let point_response = ui.interact(point_rect, second_point_id, point_sense);
+        let mut first_moved = false;
 
         if point_response.dragged() {
-            if let Some(pointer_pos) = point_response.interact_pointer_pos() {
-                match self.orientation {
-                    SliderOrientation::Horizontal => {
-                        *self.second_slider =
-                            self.slider_pos_to_val(pointer_pos.x - response.rect.left());
-                    }
-                    SliderOrientation::Vertical => {
-                        *self.second_slider =
-                            self.slider_pos_to_val(pointer_pos.y - response.rect.top());
-                    }
-                }
-                response.mark_changed();
+            let pos = self.val_to_slider_pos(*self.second_slider);
+            let (pos, _) = self.drag_slider_pos(ui, &point_response, (pos, pos));
+            *self.second_slider =
+                self.snap_to_marker(pos, self.round_value(self.slider_pos_to_val(pos)));
+            if self.swap_crossed() {
+                swapped = !swapped;
+                first_moved = true;
             }
+            response.mark_changed();
+        }
+
+        if point_response.double_clicked() {
+            self.reset_values(false, true, &mut response);
         }
 
         // handle logic
-        if self.first_slider_f64() > self.second_slider_f64() - self.separation_distance_f64() {
-            if self.push_by_dragging {
-                *self.first_slider =
-                    self.f64_to_val(self.second_slider_f64() - self.separation_distance_f64());
-            } else {
-                *self.second_slider =
-                    self.f64_to_val(self.first_slider_f64() + self.separation_distance_f64());
+        self.resolve_collision(first_moved);
+        let second_point_dragged = point_response.dragged();
+        let second_point_hovered = point_response.hovered();
+        let points_dragged = first_point_dragged || second_point_dragged;
+        ui.data_mut(|data| data.insert_temp(swapped_id, swapped && points_dragged));
+
+        let mut second_point_state =
+            InteractionState::from_response(&point_response).with_focus(has_focus);
+
+        // overlapping control points are highlighted together, as either of them can be grabbed
+        if overlapping && !points_dragged {
+            if first_point_hovered {
+                second_point_state = first_point_state;
+            } else if second_point_hovered {
+                first_point_state = second_point_state;
             }
         }
-        *self.first_slider = self.clamp_to_range(self.first_slider);
-        *self.second_slider = self.clamp_to_range(self.second_slider);
-
-        let right_circle_stroke = ui.style().interact(&point_response).fg_stroke;
         response |= point_response;
 
-        // override all shapes before drawing, due to logic limits (calculated above)
-        if !self.inverted_highlighting {
-            let in_between_rect;
-            match self.orientation {
-                SliderOrientation::Horizontal => {
-                    in_between_rect = Rect::from_min_max(
-                        to_screen.transform_pos(Pos2 {
-                            x: self.val_to_slider_pos(*self.first_slider),
-                            y: accros_slider_size / 2.0 - stroke_style.width / 2.0 + OFFSET / 2.0,
-                        }),
-                        to_screen.transform_pos(Pos2 {
-                            x: self.val_to_slider_pos(*self.second_slider),
-                            y: accros_slider_size / 2.0 + stroke_style.width / 2.0 - OFFSET / 2.0,
-                        }),
-                    );
-                }
-                SliderOrientation::Vertical => {
-                    in_between_rect = Rect::from_min_max(
-                        to_screen.transform_pos(Pos2 {
-                            x: accros_slider_size / 2.0 - stroke_style.width / 2.0 + OFFSET / 2.0,
-                            y: self.val_to_slider_pos(*self.second_slider),
-                        }),
-                        to_screen.transform_pos(Pos2 {
-                            x: accros_slider_size / 2.0 + stroke_style.width / 2.0 - OFFSET / 2.0,
-                            y: self.val_to_slider_pos(*self.first_slider),
-                        }),
-                    );
+        if self.context_menu && interactive {
+            self.show_context_menu(&mut response, logarithmic_id);
+        }
+
+        // focus the slider when interacting with it, so that it receives keyboard events
+        if response.clicked() || response.drag_started() {
+            response.request_focus();
+        }
+        if self.clipboard && interactive && has_focus {
+            for event in ui.input(|i| i.events.clone()) {
+                match event {
+                    Event::Copy => ui.ctx().copy_text(range_text::format_range(
+                        *self.first_slider,
+                        *self.second_slider,
+                    )),
+                    Event::Paste(text) if self.paste_range(&text) => response.mark_changed(),
+                    _ => {}
                 }
             }
+        }
+        if let (Some(history), true) = (&mut history, interactive && has_focus) {
+            // the more specific shortcut first, as extra shift modifiers are ignored
+            let (redo, undo) = ui.input_mut(|i| {
+                (
+                    i.consume_key(Modifiers::COMMAND | Modifiers::SHIFT, Key::Z),
+                    i.consume_key(Modifiers::COMMAND, Key::Z),
+                )
+            });
+            if redo {
+                history.redo();
+            } else if undo {
+                history.undo();
+            }
+            self.apply_history(history, &mut response);
+        }
 
-            shapes.push(Shape::Rect(RectShape::new(
-                in_between_rect,
-                0.0,
-                stroke_style.color,
-                stroke,
-                StrokeKind::Middle,
-            )));
+        // override all shapes before drawing, due to logic limits (calculated above)
+        let (start_pos, end_pos) = self.positions_along_range(origin);
+        let band_rects = if self.inverted_highlighting {
+            vec![
+                track.segment(track.start(), start_pos, false, true),
+                track.segment(end_pos, track.end(), true, false),
+            ]
         } else {
-            let first_rect;
-            let second_rect;
-            match self.orientation {
-                SliderOrientation::Horizontal => {
-                    first_rect = Rect::from_min_max(
-                        to_screen.transform_pos(Pos2 {
-                            x: self.control_point_radius,
-                            y: accros_slider_size / 2.0 - stroke_style.width / 2.0,
-                        }),
-                        to_screen.transform_pos(Pos2 {
-                            x: self.val_to_slider_pos(*self.first_slider),
-                            y: accros_slider_size / 2.0 + stroke_style.width / 2.0,
-                        }),
-                    );
-
-                    second_rect = Rect::from_min_max(
-                        to_screen.transform_pos(Pos2 {
-                            x: self.val_to_slider_pos(*self.second_slider),
-                            y: accros_slider_size / 2.0 - stroke_style.width / 2.0,
-                        }),
-                        to_screen.transform_pos(Pos2 {
-                            x: self.slider_px_size - self.control_point_radius,
-                            y: accros_slider_size / 2.0 + stroke_style.width / 2.0,
-                        }),
-                    );
-                }
-                SliderOrientation::Vertical => {
-                    first_rect = Rect::from_min_max(
-                        to_screen.transform_pos(Pos2 {
-                            x: accros_slider_size / 2.0 - stroke_style.width / 2.0,
-                            y: self.control_point_radius,
-                        }),
-                        to_screen.transform_pos(Pos2 {
-                            x: accros_slider_size / 2.0 + stroke_style.width / 2.0,
-                            y: self.val_to_slider_pos(*self.first_slider),
-                        }),
-                    );
-
-                    second_rect = Rect::from_min_max(
-                        to_screen.transform_pos(Pos2 {
-                            x: accros_slider_size / 2.0 - stroke_style.width / 2.0,
-                            y: self.val_to_slider_pos(*self.second_slider),
-                        }),
-                        to_screen.transform_pos(Pos2 {
-                            x: accros_slider_size / 2.0 + stroke_style.width / 2.0,
-                            y: self.slider_px_size - self.control_point_radius,
-                        }),
-                    );
-                }
+            vec![track.segment(start_pos, end_pos, true, true)]
+        };
+        for rect in band_rects {
+            if let Some(gradient) = &self.band_gradient {
+                shapes.push(Shape::mesh(gradient.mesh(
+                    rect,
+                    track.corner_radius,
+                    &mapping,
+                )));
+                shapes.push(Shape::Rect(RectShape::stroke(
+                    rect,
+                    track.corner_radius,
+                    stroke,
+                    StrokeKind::Inside,
+                )));
+            } else {
+                shapes.push(Shape::Rect(RectShape::new(
+                    rect,
+                    track.corner_radius,
+                    band_visuals.fill,
+                    stroke,
+                    StrokeKind::Inside,
+                )));
             }
+        }
 
-            shapes.push(Shape::Rect(RectShape::new(
-                first_rect,
-                0.0,
-                stroke_style.color,
-                Stroke::new(0.0, stroke_style.color),
-                StrokeKind::Middle,
-            )));
-            shapes.push(Shape::Rect(RectShape::new(
-                second_rect,
-                0.0,
-                stroke_style.color,
-                Stroke::new(0.0, stroke_style.color),
-                StrokeKind::Middle,
-            )));
-        }
-
-        let first_point_in_screen;
-        let second_point_in_screen;
-        match self.orientation {
-            SliderOrientation::Horizontal => {
-                first_point_in_screen = to_screen.transform_pos(Pos2 {
+        let (first_point_in_screen, second_point_in_screen) = match self.orientation {
+            SliderOrientation::Horizontal => (
+                to_screen.transform_pos(Pos2 {
                     x: self.val_to_slider_pos(*self.first_slider),
                     y: self.control_point_radius + OFFSET,
-                });
-                second_point_in_screen = to_screen.transform_pos(Pos2 {
+                }),
+                to_screen.transform_pos(Pos2 {
                     x: self.val_to_slider_pos(*self.second_slider),
                     y: self.control_point_radius + OFFSET,
-                });
-            }
-            SliderOrientation::Vertical => {
-                first_point_in_screen = to_screen.transform_pos(Pos2 {
+                }),
+            ),
+            SliderOrientation::Vertical => (
+                to_screen.transform_pos(Pos2 {
                     x: self.control_point_radius + OFFSET,
                     y: self.val_to_slider_pos(*self.first_slider),
-                });
-                second_point_in_screen = to_screen.transform_pos(Pos2 {
+                }),
+                to_screen.transform_pos(Pos2 {
                     x: self.control_point_radius + OFFSET,
                     y: self.val_to_slider_pos(*self.second_slider),
-                });
+                }),
+            ),
+        };
+
+        // draw the in-between part
+        painter.extend(shapes);
+
+        // draw the markers on top of the line, with their tooltips
+        for (index, marker) in self.visible_markers().enumerate() {
+            let color = marker.color.unwrap_or(ui.visuals().text_color());
+            let pos = mapping.position_from_value(marker.value.to_f64());
+            let (line, label_pos, align) = layout.marker(slider_rect, pos);
+            painter.line_segment(line, Stroke::new(1.0, color));
+            let mut hover_rect =
+                Rect::from_two_pos(line[0], line[1]).expand(MARKER_HOVER_WIDTH / 2.0);
+            if !marker.label.is_empty() {
+                let galley = painter.layout_no_wrap(marker.label.clone(), tick_font.clone(), color);
+                let label_rect =
+                    layout.keep_inside(align.anchor_size(label_pos, galley.size()), response.rect);
+                hover_rect = hover_rect.union(label_rect);
+                painter.galley(label_rect.min, galley, color);
             }
+            let tooltip = marker.tooltip.clone().unwrap_or_else(|| {
+                let value = self.value_label(marker.value);
+                if marker.label.is_empty() {
+                    value
+                } else {
+                    format!("{}: {value}", marker.label)
+                }
+            });
+            ui.interact(
+                hover_rect,
+                response.id.with(("marker", index)),
+                Sense::hover(),
+            )
+            .on_hover_text(tooltip);
         }
 
-        shapes.push(Shape::Circle(CircleShape {
-            center: first_point_in_screen,
-            radius: self.control_point_radius,
-            fill: cursor_fill,
-            stroke: left_circle_stroke,
-        }));
-
-        shapes.push(Shape::Circle(CircleShape {
-            center: second_point_in_screen,
-            radius: self.control_point_radius,
-            fill: cursor_fill,
-            stroke: right_circle_stroke,
-        }));
+        let hovered_together =
+            overlapping && !points_dragged && (first_point_hovered || second_point_hovered);
+        let mut points = [
+            (
+                first_point_in_screen,
+                HandleState {
+                    handle: Handle::Lower,
+                    orientation: self.orientation,
+                    reversed: self.reversed(),
+                    hovered: first_point_hovered || hovered_together,
+                    enabled: ui.is_enabled(),
+                    dragged: first_point_dragged,
+                    fill: style.lower_handle.visuals(first_point_state).fill,
+                    stroke: style.lower_handle.visuals(first_point_state).stroke,
+                },
+            ),
+            (
+                second_point_in_screen,
+                HandleState {
+                    handle: Handle::Upper,
+                    orientation: self.orientation,
+                    reversed: self.reversed(),
+                    hovered: second_point_hovered || hovered_together,
+                    enabled: ui.is_enabled(),
+                    dragged: second_point_dragged,
+                    fill: style.upper_handle.visuals(second_point_state).fill,
+                    stroke: style.upper_handle.visuals(second_point_state).stroke,
+                },
+            ),
+        ];
+        // the dragged control point is drawn on top
+        if first_point_dragged {
+            points.reverse();
+        }
 
         // draw control points
-        painter.extend(shapes);
+        for (center, state) in points {
+            let rect = Rect::from_center_size(center, size);
+            if let Some(paint_handle) = &self.handle_painter {
+                paint_handle(&painter, rect, state);
+            } else if self.lock_span {
+                HandleShape::Bar.paint(&painter, rect, state);
+            } else {
+                match state.handle {
+                    Handle::Lower => self.lower_handle_shape.paint(&painter, rect, state),
+                    Handle::Upper => self.upper_handle_shape.paint(&painter, rect, state),
+                }
+            }
+        }
+
+        // draw the values next to the control points
+        if self.value_labels {
+            let color = ui.visuals().text_color();
+            let mut labels = [*self.first_slider, *self.second_slider].map(|value| {
+                let galley =
+                    painter.layout_no_wrap(self.value_label(value), value_font.clone(), color);
+                let pos = origin + self.val_to_slider_pos(value);
+                (
+                    layout.value_label_rect(slider_rect, pos, galley.size()),
+                    galley,
+                )
+            });
+            // the labels are placed in their order along the slider
+            if self.reversed() {
+                labels.reverse();
+            }
+            let label_rects = layout.place_value_labels([labels[0].0, labels[1].0], response.rect);
+            for ((_, galley), label_rect) in labels.into_iter().zip(label_rects) {
+                painter.galley(label_rect.min, galley, color);
+            }
+        }
 
         let zoom_id = response.id.with(4);
         let zoom_response = ui.interact(response.rect, zoom_id, Sense::hover());
 
         // scroll through time axis
-        if zoom_response.hovered() {
+        let mut scrolling = false;
+        if interactive && zoom_response.hovered() {
             let raw_scroll_delta = ui.ctx().input(|i| i.smooth_scroll_delta);
             let mut scroll_delta = 0.0;
             if self.horizontal_scroll {
@@ -716,34 +1998,128 @@ impl<'a, T: Numeric> Widget for DoubleSlider<'a, T> {
             if self.vertical_scroll {
                 scroll_delta += raw_scroll_delta.y * self.scroll_factor;
             }
-            let zoom_delta = self.zoom_factor * (ui.ctx().input(|i| i.zoom_delta() - 1.0));
+            let zoom_delta = if self.lock_span {
+                0.0
+            } else {
+                self.zoom_factor * (ui.ctx().input(|i| i.zoom_delta() - 1.0))
+            };
+            // the values move in the same direction on the screen (right or up), whichever way the range goes
+            let (value_scroll_delta, pos_zoom_delta) = if self.reversed() {
+                (-scroll_delta as f64, -zoom_delta)
+            } else {
+                (scroll_delta as f64, zoom_delta)
+            };
+            // positions grow downwards on a vertical slider
+            let (pos_scroll_delta, pos_zoom_delta) = match self.orientation {
+                SliderOrientation::Horizontal => (scroll_delta, pos_zoom_delta),
+                SliderOrientation::Vertical => (-scroll_delta, -pos_zoom_delta),
+            };
 
-            if self.logarithmic {
-                *self.first_slider = self
-                    .slider_pos_to_val(self.val_to_slider_pos(*self.first_slider) + scroll_delta);
-                *self.second_slider = self
-                    .slider_pos_to_val(self.val_to_slider_pos(*self.second_slider) + scroll_delta);
+            // values are only converted when they move, as the conversion is not exact on a logarithmic scale
+            if scroll_delta != 0.0 || zoom_delta != 0.0 {
+                scrolling = true;
+
+                // rounding would swallow small steps, so they add up on the unrounded values of the previous steps
+                let unrounded_id = response.id.with("unrounded");
+                let rounded = [self.first_slider.to_f64(), self.second_slider.to_f64()];
+                if let Some([_, _, first, second]) = ui
+                    .data(|data| data.get_temp::<[f64; 4]>(unrounded_id))
+                    .filter(|values| values[..2] == rounded)
+                {
+                    *self.first_slider = T::from_f64(first);
+                    *self.second_slider = T::from_f64(second);
+                }
 
-                *self.first_slider =
-                    self.slider_pos_to_val(self.val_to_slider_pos(*self.first_slider) + zoom_delta);
-                *self.second_slider = self
-                    .slider_pos_to_val(self.val_to_slider_pos(*self.second_slider) - zoom_delta);
-            } else {
-                *self.first_slider = self.f64_to_val(self.first_slider_f64() + scroll_delta as f64);
-                *self.second_slider =
-                    self.f64_to_val(self.second_slider_f64() + scroll_delta as f64);
+                if let Some(span) = locked_span {
+                    let first = if self.logarithmic {
+                        self.slider_pos_to_val(
+                            self.val_to_slider_pos(*self.first_slider) + pos_scroll_delta,
+                        )
+                    } else {
+                        value::offset(*self.first_slider, value_scroll_delta)
+                    };
+                    self.move_window(first, span);
+                } else if self.logarithmic {
+                    *self.first_slider = self.slider_pos_to_val(
+                        self.val_to_slider_pos(*self.first_slider) + pos_scroll_delta,
+                    );
+                    *self.second_slider = self.slider_pos_to_val(
+                        self.val_to_slider_pos(*self.second_slider) + pos_scroll_delta,
+                    );
 
-                *self.second_slider = self.f64_to_val(self.second_slider_f64() + zoom_delta as f64);
-                *self.first_slider = self.f64_to_val(self.first_slider_f64() - zoom_delta as f64);
+                    *self.first_slider = self.slider_pos_to_val(
+                        self.val_to_slider_pos(*self.first_slider) - pos_zoom_delta,
+                    );
+                    *self.second_slider = self.slider_pos_to_val(
+                        self.val_to_slider_pos(*self.second_slider) + pos_zoom_delta,
+                    );
+                } else {
+                    *self.first_slider = value::offset(*self.first_slider, value_scroll_delta);
+                    *self.second_slider = value::offset(*self.second_slider, value_scroll_delta);
+
+                    *self.second_slider = value::offset(*self.second_slider, zoom_delta as f64);
+                    *self.first_slider = value::offset(*self.first_slider, -zoom_delta as f64);
+                }
+
+                // zooming out stops at the maximum span
+                if let Some(max_span) = self.max_span {
+                    let excess = value::difference(*self.first_slider, *self.second_slider)
+                        - max_span.to_f64();
+                    if excess > 0.0 {
+                        *self.first_slider = value::offset(*self.first_slider, excess / 2.0);
+                        *self.second_slider = value::offset(*self.second_slider, -excess / 2.0);
+                    }
+                }
+
+                *self.first_slider = self.clamp_to_range(self.first_slider);
+                *self.second_slider = self.clamp_to_range(self.second_slider);
+                if self.rounds_values() {
+                    let unrounded = [self.first_slider.to_f64(), self.second_slider.to_f64()];
+                    self.round_values();
+                    let rounded = [self.first_slider.to_f64(), self.second_slider.to_f64()];
+                    ui.data_mut(|data| {
+                        data.insert_temp(
+                            unrounded_id,
+                            [rounded[0], rounded[1], unrounded[0], unrounded[1]],
+                        )
+                    });
+                }
+                response.mark_changed();
             }
+        }
 
-            *self.first_slider = self.clamp_to_range(self.first_slider);
-            *self.second_slider = self.clamp_to_range(self.second_slider);
+        if (self.correct_values() || corrected) && interactive {
+            response.mark_changed();
+        }
 
-            if scroll_delta != 0.0 || zoom_delta != 0.0 {
-                response.mark_changed()
+        // interacting with the slider stops the transition to a preset
+        if animating && (response.dragged() || scrolling) {
+            PresetAnimation::clear(ui.ctx(), response.id);
+        }
+
+        // a selection changed by the user is committed to the history once it settles,
+        // i.e. nothing is dragged, scrolled or animated
+        if let Some(mut history) = history {
+            if (*self.first_slider, *self.second_slider) != initial_values {
+                SliderHistory::mark_interaction(ui.ctx(), response.id);
             }
+            let settled = ui.ctx().dragged_id().is_none() && !scrolling && !animating;
+            if settled && SliderHistory::take_interaction(ui.ctx(), response.id) {
+                history.commit(*self.first_slider, *self.second_slider);
+            }
+            history.store(ui.ctx(), response.id);
         }
         response
     }
 }
+
+impl<'a, T: Numeric> Widget for DoubleSlider<'a, T> {
+    fn ui(self, ui: &mut Ui) -> egui::Response {
+        // read-only and disabled sliders correct and draw copies, the values of the application are left alone
+        if !ui.is_enabled() || self.read_only {
+            let (mut first, mut second) = (*self.first_slider, *self.second_slider);
+            return self.detached(&mut first, &mut second).add_to(ui);
+        }
+        self.add_to(ui)
+    }
+}
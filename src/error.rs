@@ -0,0 +1,74 @@
+use std::fmt;
+
+/// An invalid configuration of a [`crate::DoubleSlider`], see [`crate::DoubleSlider::validate`].
+///
+/// A slider with an invalid configuration is shown in an error state instead of panicking.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DoubleSliderError {
+    /// An end of the range is NaN or infinite.
+    NonFiniteRange { start: f64, end: f64 },
+
+    /// The logarithmic scale is used with a range that includes zero or negative values.
+    LogarithmicRange { start: f64, end: f64 },
+
+    /// The separation distance is negative or NaN.
+    InvalidSeparation(f64),
+
+    /// The maximum span is negative or NaN.
+    InvalidMaxSpan(f64),
+
+    /// The separation distance is larger than the maximum span, so no selection satisfies both.
+    SeparationExceedsMaxSpan { separation: f64, max_span: f64 },
+
+    /// The values are rounded to zero significant digits.
+    InvalidSignificantDigits,
+
+    /// The size or the minimum size is zero, negative or not finite.
+    InvalidSize(f32),
+
+    /// The control point radius is negative or not finite.
+    InvalidControlPointRadius(f32),
+
+    /// The control points do not fit into the size of the slider.
+    ControlPointTooLarge { radius: f32, size: f32 },
+}
+
+impl fmt::Display for DoubleSliderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonFiniteRange { start, end } => {
+                write!(f, "the range {start}..={end} is not finite")
+            }
+            Self::LogarithmicRange { start, end } => write!(
+                f,
+                "the logarithmic scale needs a range of strictly positive values, not {start}..={end}"
+            ),
+            Self::InvalidSeparation(separation) => {
+                write!(f, "the separation distance {separation} is negative or NaN")
+            }
+            Self::InvalidMaxSpan(max_span) => {
+                write!(f, "the maximum span {max_span} is negative or NaN")
+            }
+            Self::SeparationExceedsMaxSpan {
+                separation,
+                max_span,
+            } => write!(
+                f,
+                "the separation distance {separation} is larger than the maximum span {max_span}"
+            ),
+            Self::InvalidSignificantDigits => {
+                write!(f, "the values cannot be rounded to zero significant digits")
+            }
+            Self::InvalidSize(size) => write!(f, "the size {size} is not positive"),
+            Self::InvalidControlPointRadius(radius) => {
+                write!(f, "the control point radius {radius} is negative")
+            }
+            Self::ControlPointTooLarge { radius, size } => write!(
+                f,
+                "control points of radius {radius} do not fit into the size {size}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DoubleSliderError {}
@@ -0,0 +1,134 @@
+use egui::emath::{Pos2, Rect};
+use egui::epaint::{Color32, Mesh};
+use egui::SliderOrientation;
+
+use crate::mapping::SliderMapping;
+
+// distance between the samples of a gradient given by a function, in points
+const SAMPLE_DISTANCE: f32 = 2.0;
+
+// samples along each rounded end of the mesh
+const CORNER_SAMPLES: usize = 4;
+
+/// Colors along the slider, e.g. to show a color scale, see [`crate::DoubleSlider::track_gradient`].
+///
+/// Both kinds are evaluated in the scale of the slider, so a logarithmic slider shows a logarithmic color scale.
+pub enum Gradient<'a> {
+    /// Colors at the given values, interpolated linearly between them on the screen.
+    /// Values outside of the stops get the color of the nearest stop.
+    Stops(Vec<(f64, Color32)>),
+
+    /// The color of each value, sampled every few points along the slider.
+    Function(Box<dyn Fn(f64) -> Color32 + 'a>),
+}
+
+impl<'a> Gradient<'a> {
+    /// A gradient through the given `(value, color)` stops, in any order.
+    pub fn stops(stops: impl IntoIterator<Item = (f64, Color32)>) -> Self {
+        let mut stops: Vec<_> = stops.into_iter().collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self::Stops(stops)
+    }
+
+    /// A gradient mapping each value to a color, e.g. a colormap.
+    pub fn function(color: impl Fn(f64) -> Color32 + 'a) -> Self {
+        Self::Function(Box::new(color))
+    }
+
+    fn color_at(&self, value: f64, mapping: &SliderMapping) -> Color32 {
+        match self {
+            Self::Function(color) => color(value),
+            Self::Stops(stops) => {
+                let Some(next) = stops.iter().position(|(stop, _)| *stop >= value) else {
+                    return stops
+                        .last()
+                        .map_or(Color32::TRANSPARENT, |(_, color)| *color);
+                };
+                if next == 0 {
+                    return stops[0].1;
+                }
+                let ((low, low_color), (high, high_color)) = (stops[next - 1], stops[next]);
+                let (low_pos, high_pos) = (
+                    mapping.position_from_value(low),
+                    mapping.position_from_value(high),
+                );
+                let t = if low_pos == high_pos {
+                    1.0
+                } else {
+                    (mapping.position_from_value(value) - low_pos) / (high_pos - low_pos)
+                };
+                low_color.lerp_to_gamma(high_color, t.clamp(0.0, 1.0))
+            }
+        }
+    }
+
+    /// A mesh filling `rect` with the gradient along the slider, with rounded corners.
+    pub(crate) fn mesh(&self, rect: Rect, corner_radius: f32, mapping: &SliderMapping) -> Mesh {
+        let horizontal = mapping.orientation() == SliderOrientation::Horizontal;
+        let (start, end, across) = if horizontal {
+            (rect.left(), rect.right(), rect.height())
+        } else {
+            (rect.top(), rect.bottom(), rect.width())
+        };
+        let corner_radius = corner_radius
+            .min(across / 2.0)
+            .min((end - start) / 2.0)
+            .max(0.0);
+
+        // linear segments between the stops are exact, functions need to be sampled
+        let mut positions = vec![start, end];
+        match self {
+            Self::Stops(stops) => positions.extend(
+                stops
+                    .iter()
+                    .map(|(stop, _)| mapping.position_from_value(*stop))
+                    .filter(|pos| (start..=end).contains(pos)),
+            ),
+            Self::Function(_) => {
+                let samples = ((end - start) / SAMPLE_DISTANCE).ceil() as usize;
+                positions.extend(
+                    (1..samples).map(|i| start + (end - start) * i as f32 / samples as f32),
+                );
+            }
+        }
+        if corner_radius > 0.0 {
+            positions.extend((1..CORNER_SAMPLES).flat_map(|i| {
+                let distance = corner_radius * i as f32 / CORNER_SAMPLES as f32;
+                [start + distance, end - distance]
+            }));
+        }
+        positions.sort_by(f32::total_cmp);
+        positions.dedup();
+
+        let mut mesh = Mesh::default();
+        for (i, pos) in positions.into_iter().enumerate() {
+            let color = self.color_at(mapping.value_from_position(pos), mapping);
+            // narrower within the rounded corners at the ends
+            let distance = corner_radius - (pos - start).min(end - pos);
+            let inset = if distance > 0.0 {
+                corner_radius - (corner_radius * corner_radius - distance * distance).sqrt()
+            } else {
+                0.0
+            };
+            let (a, b) = if horizontal {
+                (
+                    Pos2::new(pos, rect.top() + inset),
+                    Pos2::new(pos, rect.bottom() - inset),
+                )
+            } else {
+                (
+                    Pos2::new(rect.left() + inset, pos),
+                    Pos2::new(rect.right() - inset, pos),
+                )
+            };
+            mesh.colored_vertex(a, color);
+            mesh.colored_vertex(b, color);
+            if i > 0 {
+                let index = 2 * i as u32;
+                mesh.add_triangle(index - 2, index - 1, index);
+                mesh.add_triangle(index - 1, index, index + 1);
+            }
+        }
+        mesh
+    }
+}
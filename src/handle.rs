@@ -0,0 +1,138 @@
+use egui::emath::{Pos2, Rect, Vec2};
+use egui::epaint::{CircleShape, Color32, PathShape, RectShape, Shape, Stroke};
+use egui::{Painter, SliderOrientation, StrokeKind};
+
+/// One of the two control points of a [`crate::DoubleSlider`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Handle {
+    /// The control point of the lower value.
+    Lower,
+
+    /// The control point of the upper value.
+    Upper,
+}
+
+/// The state of a control point, passed to [`crate::DoubleSlider::paint_handle`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HandleState {
+    /// Which control point is painted.
+    pub handle: Handle,
+
+    /// Orientation of the slider.
+    pub orientation: SliderOrientation,
+
+    /// The range of the slider goes from high to low,
+    /// so the lower control point is nearer to the end of the slider (the right or the top).
+    pub reversed: bool,
+
+    /// The slider is enabled, see [`egui::Ui::is_enabled`].
+    pub enabled: bool,
+
+    /// The pointer is hovering the control point (or both, if they overlap).
+    pub hovered: bool,
+
+    /// The control point is being dragged.
+    pub dragged: bool,
+
+    /// Fill color that the built-in shapes would use.
+    pub fill: Color32,
+
+    /// Stroke that the built-in shapes would use.
+    pub stroke: Stroke,
+}
+
+/// Built-in shapes of the control points.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum HandleShape {
+    /// A circle, the same as [`egui::Slider`].
+    #[default]
+    Circle,
+
+    /// A rectangle with rounded corners.
+    RoundedRect { corner_radius: f32 },
+
+    /// A thin bar across the line.
+    Bar,
+
+    /// A triangle pointing at the line.
+    Triangle,
+
+    /// A bracket opening towards the selected range, `[` for the lower and `]` for the upper control point
+    /// (the other way around if the range goes from high to low).
+    Bracket,
+}
+
+impl HandleShape {
+    /// Paint the shape into `rect`, which is the interactive area of the control point.
+    pub fn paint(self, painter: &Painter, rect: Rect, state: HandleState) {
+        let horizontal = state.orientation == SliderOrientation::Horizontal;
+        let center = rect.center();
+        match self {
+            HandleShape::Circle => {
+                painter.add(CircleShape {
+                    center,
+                    radius: rect.width().min(rect.height()) / 2.0,
+                    fill: state.fill,
+                    stroke: state.stroke,
+                });
+            }
+            HandleShape::RoundedRect { corner_radius } => {
+                painter.add(RectShape::new(
+                    rect,
+                    corner_radius,
+                    state.fill,
+                    state.stroke,
+                    StrokeKind::Middle,
+                ));
+            }
+            HandleShape::Bar => {
+                let thickness = rect.width().min(rect.height()) / 3.0;
+                let size = if horizontal {
+                    Vec2::new(thickness, rect.height())
+                } else {
+                    Vec2::new(rect.width(), thickness)
+                };
+                painter.add(RectShape::new(
+                    Rect::from_center_size(center, size),
+                    thickness / 2.0,
+                    state.fill,
+                    state.stroke,
+                    StrokeKind::Middle,
+                ));
+            }
+            HandleShape::Triangle => {
+                let points = if horizontal {
+                    vec![rect.left_top(), rect.right_top(), rect.center_bottom()]
+                } else {
+                    vec![rect.right_top(), rect.right_bottom(), rect.left_center()]
+                };
+                painter.add(Shape::convex_polygon(points, state.fill, state.stroke));
+            }
+            HandleShape::Bracket => {
+                // the open side of the bracket faces the selected range
+                let depth = rect.width().min(rect.height()) / 3.0;
+                let at_start = (state.handle == Handle::Lower) != state.reversed;
+                let inwards = match (at_start, horizontal) {
+                    (true, true) => Vec2::new(depth, 0.0),
+                    (false, true) => Vec2::new(-depth, 0.0),
+                    // the start of the range is at the bottom of a vertical slider
+                    (true, false) => Vec2::new(0.0, -depth),
+                    (false, false) => Vec2::new(0.0, depth),
+                };
+                let (a, b): (Pos2, Pos2) = if horizontal {
+                    (rect.center_top(), rect.center_bottom())
+                } else {
+                    (rect.left_center(), rect.right_center())
+                };
+                let stroke = Stroke::new(
+                    (2.0 * state.stroke.width).max(depth / 2.0),
+                    state.stroke.color,
+                );
+                painter.add(PathShape::line(
+                    vec![a + inwards, a, b, b + inwards],
+                    stroke,
+                ));
+            }
+        }
+    }
+}
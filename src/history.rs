@@ -0,0 +1,189 @@
+use egui::emath::Numeric;
+use egui::{Context, Id};
+
+use crate::value;
+
+// number of selections that are kept
+const MAX_ENTRIES: usize = 100;
+
+// A selection stored independently of the value type, exactly for all of them (see `value::to_i128`)
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Selection {
+    Float(f64, f64),
+    Integer(i128, i128),
+}
+
+impl Selection {
+    fn new<T: Numeric>(low: T, high: T) -> Self {
+        match (value::to_i128(low), value::to_i128(high)) {
+            (Some(low), Some(high)) => Self::Integer(low, high),
+            _ => Self::Float(low.to_f64(), high.to_f64()),
+        }
+    }
+
+    fn get<T: Numeric>(self) -> (T, T) {
+        match self {
+            Self::Float(low, high) => (T::from_f64(low), T::from_f64(high)),
+            Self::Integer(low, high) => (
+                value::from_i128(low).unwrap_or_else(|| T::from_f64(low as f64)),
+                value::from_i128(high).unwrap_or_else(|| T::from_f64(high as f64)),
+            ),
+        }
+    }
+}
+
+/// The committed selections of a [`crate::DoubleSlider`] with [`crate::DoubleSlider::history`], to undo and redo changes.
+///
+/// A selection is committed when the user changed it and it settles, i.e. when a drag is released, scrolling and zooming stop,
+/// or after pasting, a context menu action or a preset. Values set by the application are not committed.
+/// While the slider has keyboard focus, `Ctrl+Z` goes back and `Ctrl+Shift+Z` forward in the history.
+/// The last 100 selections are kept.
+///
+/// The history is kept in the egui memory of the slider and can also be navigated by the application.
+/// After moving through the history, it must be stored again, and the slider sets its values to the
+/// current selection the next time it is shown:
+///
+/// ```
+/// use egui_double_slider::{DoubleSlider, SliderHistory};
+///
+/// egui::__run_test_ui(|ui| {
+///     let (mut low, mut high) = (20.0, 80.0);
+///     let response = ui.add(DoubleSlider::new(&mut low, &mut high, 0.0..=100.0).history(true));
+///     if let Some(mut history) = SliderHistory::load(ui.ctx(), response.id) {
+///         if history.can_undo() && ui.button("Back").clicked() {
+///             history.undo();
+///             history.store(ui.ctx(), response.id);
+///         }
+///     }
+/// });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SliderHistory {
+    entries: Vec<Selection>,
+    current: usize,
+    // the current selection was moved to by the application and has not been applied to the values yet
+    pending: bool,
+}
+
+impl SliderHistory {
+    pub(crate) fn new<T: Numeric>(low: T, high: T) -> Self {
+        Self {
+            entries: vec![Selection::new(low, high)],
+            current: 0,
+            pending: false,
+        }
+    }
+
+    /// The history of the slider with the given id (see [`egui::Response::id`]), if it has one.
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data(|data| data.get_temp(Self::id(id)))
+    }
+
+    /// Keep the history for the slider with the given id, which applies the current selection
+    /// the next time it is shown if it was changed with [`Self::undo`], [`Self::redo`] or [`Self::go_to`].
+    pub fn store(self, ctx: &Context, id: Id) {
+        if self.pending {
+            ctx.request_repaint();
+        }
+        ctx.data_mut(|data| data.insert_temp(Self::id(id), self));
+    }
+
+    /// Forget the history of the slider with the given id. It starts again with the selection the next time it is shown.
+    pub fn clear(ctx: &Context, id: Id) {
+        ctx.data_mut(|data| data.remove::<Self>(Self::id(id)));
+    }
+
+    fn id(slider_id: Id) -> Id {
+        slider_id.with("history")
+    }
+
+    fn interaction_id(slider_id: Id) -> Id {
+        slider_id.with("history interaction")
+    }
+
+    // Notes that the user changed the values, so that they are committed once the interaction settles
+    pub(crate) fn mark_interaction(ctx: &Context, slider_id: Id) {
+        ctx.data_mut(|data| data.insert_temp(Self::interaction_id(slider_id), true));
+    }
+
+    // Whether the user changed the values since the last call, forgetting it
+    pub(crate) fn take_interaction(ctx: &Context, slider_id: Id) -> bool {
+        ctx.data_mut(|data| data.remove_temp(Self::interaction_id(slider_id)))
+            .unwrap_or(false)
+    }
+
+    /// The number of selections in the history, at least 1.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The history has no selections, which never happens as it starts with the first one.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The index of the current selection, from 0 for the oldest one.
+    pub fn index(&self) -> usize {
+        self.current
+    }
+
+    /// The selection at `index`, from 0 for the oldest one.
+    pub fn get<T: Numeric>(&self, index: usize) -> Option<(T, T)> {
+        self.entries.get(index).map(|selection| selection.get())
+    }
+
+    /// The current selection.
+    pub fn current<T: Numeric>(&self) -> (T, T) {
+        self.entries[self.current].get()
+    }
+
+    /// There is an older selection to go back to.
+    pub fn can_undo(&self) -> bool {
+        self.current > 0
+    }
+
+    /// There is a newer selection to go forward to.
+    pub fn can_redo(&self) -> bool {
+        self.current + 1 < self.entries.len()
+    }
+
+    /// Go back to the previous selection. Returns false if there is none.
+    pub fn undo(&mut self) -> bool {
+        self.can_undo() && self.go_to(self.current - 1)
+    }
+
+    /// Go forward to the next selection. Returns false if there is none.
+    pub fn redo(&mut self) -> bool {
+        self.can_redo() && self.go_to(self.current + 1)
+    }
+
+    /// Go to the selection at `index`. Returns false if there is none.
+    pub fn go_to(&mut self, index: usize) -> bool {
+        if index >= self.entries.len() {
+            return false;
+        }
+        self.current = index;
+        self.pending = true;
+        true
+    }
+
+    // Takes the selection that the application moved to, if any
+    pub(crate) fn take_pending<T: Numeric>(&mut self) -> Option<(T, T)> {
+        std::mem::take(&mut self.pending).then(|| self.current())
+    }
+
+    // Adds a settled selection after the current one, dropping the ones that were undone
+    pub(crate) fn commit<T: Numeric>(&mut self, low: T, high: T) -> bool {
+        let selection = Selection::new(low, high);
+        if self.entries[self.current] == selection {
+            return false;
+        }
+        self.entries.truncate(self.current + 1);
+        self.entries.push(selection);
+        if self.entries.len() > MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.current = self.entries.len() - 1;
+        true
+    }
+}
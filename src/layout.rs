@@ -0,0 +1,210 @@
+use egui::emath::{Align2, Pos2, Rect, Vec2};
+use egui::epaint::MarginF32;
+use egui::SliderOrientation;
+
+// distance between the labels and the slider
+const LABEL_SPACING: f32 = 2.0;
+
+// length of the tick marks
+const TICK_LENGTH: f32 = 4.0;
+
+/// Space taken by everything drawn around the line and the control points of a slider.
+///
+/// The line and the control points are laid out in the slider area, see [`Self::slider_rect`].
+/// Value labels are placed above a horizontal and to the right of a vertical slider, followed by marker labels,
+/// ticks and their labels below and to the left of it.
+pub(crate) struct SliderLayout {
+    pub(crate) orientation: SliderOrientation,
+
+    /// Distance of the control points at the ends of the range from the ends of the slider area.
+    pub(crate) handle_offset: f32,
+
+    /// How far the line (e.g. its focus outline) reaches beyond the ends of the slider area.
+    pub(crate) along_overhang: f32,
+
+    /// How far the control points and the line reach beyond the sides of the slider area.
+    pub(crate) across_overhang: f32,
+
+    /// Size of the largest value label, `None` without value labels.
+    pub(crate) value_label: Option<Vec2>,
+
+    /// Size of the largest tick label, `None` without ticks.
+    pub(crate) tick_label: Option<Vec2>,
+
+    /// Size of the largest marker label, `None` without labeled markers.
+    pub(crate) marker_label: Option<Vec2>,
+}
+
+impl SliderLayout {
+    /// The space needed around the slider area.
+    pub(crate) fn margin(&self) -> MarginF32 {
+        let horizontal = self.orientation == SliderOrientation::Horizontal;
+        let along = |size: Vec2| if horizontal { size.x } else { size.y };
+        let across = |size: Vec2| if horizontal { size.y } else { size.x };
+
+        // labels are centered on their position, which can be at the ends of the slider area
+        let label_overhang = [self.value_label, self.tick_label, self.marker_label]
+            .into_iter()
+            .flatten()
+            .map(|size| along(size) / 2.0 - self.handle_offset)
+            .fold(0.0, f32::max);
+        let ends = self.along_overhang.max(label_overhang);
+        let values = self.marker_label_distance()
+            + self
+                .marker_label
+                .map_or(0.0, |size| LABEL_SPACING + across(size));
+        let ticks = self.across_overhang
+            + self
+                .tick_label
+                .map_or(0.0, |size| TICK_LENGTH + across(size));
+
+        if horizontal {
+            MarginF32 {
+                left: ends,
+                right: ends,
+                top: values,
+                bottom: ticks,
+            }
+        } else {
+            MarginF32 {
+                left: ticks,
+                right: values,
+                top: ends,
+                bottom: ends,
+            }
+        }
+    }
+
+    /// The size to allocate for a slider area of the given size.
+    pub(crate) fn outer_size(&self, slider_size: Vec2) -> Vec2 {
+        slider_size + self.margin().sum()
+    }
+
+    /// The slider area within the allocated `rect`.
+    pub(crate) fn slider_rect(&self, rect: Rect) -> Rect {
+        rect - self.margin()
+    }
+
+    /// The area of a value label of the given size, for a control point at `pos` along the slider.
+    pub(crate) fn value_label_rect(&self, slider_rect: Rect, pos: f32, size: Vec2) -> Rect {
+        let distance = self.across_overhang + LABEL_SPACING;
+        match self.orientation {
+            SliderOrientation::Horizontal => Align2::CENTER_BOTTOM
+                .anchor_size(Pos2::new(pos, slider_rect.top() - distance), size),
+            SliderOrientation::Vertical => Align2::LEFT_CENTER
+                .anchor_size(Pos2::new(slider_rect.right() + distance, pos), size),
+        }
+    }
+
+    /// Distance of the marker labels from the slider area, beyond the value labels.
+    fn marker_label_distance(&self) -> f32 {
+        let across = |size: Vec2| match self.orientation {
+            SliderOrientation::Horizontal => size.y,
+            SliderOrientation::Vertical => size.x,
+        };
+        self.across_overhang
+            + self
+                .value_label
+                .map_or(0.0, |size| LABEL_SPACING + across(size))
+    }
+
+    /// The line of a marker at `pos` along the slider, across the slider area up to its label,
+    /// and where to draw the label.
+    pub(crate) fn marker(&self, slider_rect: Rect, pos: f32) -> ([Pos2; 2], Pos2, Align2) {
+        let distance = self.marker_label_distance();
+        let label_distance = distance + LABEL_SPACING;
+        match self.orientation {
+            SliderOrientation::Horizontal => (
+                [
+                    Pos2::new(pos, slider_rect.bottom()),
+                    Pos2::new(pos, slider_rect.top() - distance),
+                ],
+                Pos2::new(pos, slider_rect.top() - label_distance),
+                Align2::CENTER_BOTTOM,
+            ),
+            SliderOrientation::Vertical => (
+                [
+                    Pos2::new(slider_rect.left(), pos),
+                    Pos2::new(slider_rect.right() + distance, pos),
+                ],
+                Pos2::new(slider_rect.right() + label_distance, pos),
+                Align2::LEFT_CENTER,
+            ),
+        }
+    }
+
+    /// The tick mark at `pos` along the slider, and where to draw its label.
+    pub(crate) fn tick(&self, slider_rect: Rect, pos: f32) -> ([Pos2; 2], Pos2, Align2) {
+        let start = self.across_overhang;
+        let end = start + TICK_LENGTH;
+        match self.orientation {
+            SliderOrientation::Horizontal => {
+                let y = slider_rect.bottom();
+                (
+                    [Pos2::new(pos, y + start), Pos2::new(pos, y + end)],
+                    Pos2::new(pos, y + end),
+                    Align2::CENTER_TOP,
+                )
+            }
+            SliderOrientation::Vertical => {
+                let x = slider_rect.left();
+                (
+                    [Pos2::new(x - start, pos), Pos2::new(x - end, pos)],
+                    Pos2::new(x - end, pos),
+                    Align2::RIGHT_CENTER,
+                )
+            }
+        }
+    }
+
+    /// Moves the value labels of the control points nearer to the start and to the end of the range
+    /// apart if they overlap, and into `bounds`.
+    pub(crate) fn place_value_labels(&self, [lower, upper]: [Rect; 2], bounds: Rect) -> [Rect; 2] {
+        // the start of the range is on the left of a horizontal and at the bottom of a vertical slider
+        let (lower, upper) = match self.orientation {
+            SliderOrientation::Horizontal => {
+                let overlap = lower.right() + LABEL_SPACING - upper.left();
+                if overlap > 0.0 {
+                    (
+                        lower.translate(Vec2::new(-overlap / 2.0, 0.0)),
+                        upper.translate(Vec2::new(overlap / 2.0, 0.0)),
+                    )
+                } else {
+                    (lower, upper)
+                }
+            }
+            SliderOrientation::Vertical => {
+                let overlap = upper.bottom() + LABEL_SPACING - lower.top();
+                if overlap > 0.0 {
+                    (
+                        lower.translate(Vec2::new(0.0, overlap / 2.0)),
+                        upper.translate(Vec2::new(0.0, -overlap / 2.0)),
+                    )
+                } else {
+                    (lower, upper)
+                }
+            }
+        };
+        [
+            self.keep_inside(lower, bounds),
+            self.keep_inside(upper, bounds),
+        ]
+    }
+
+    /// Moves a label along the slider into `bounds`.
+    pub(crate) fn keep_inside(&self, rect: Rect, bounds: Rect) -> Rect {
+        let shift = |min: f32, max: f32, bounds_min: f32, bounds_max: f32| {
+            (bounds_min - min).max(0.0) + (bounds_max - max).min(0.0)
+        };
+        match self.orientation {
+            SliderOrientation::Horizontal => rect.translate(Vec2::new(
+                shift(rect.left(), rect.right(), bounds.left(), bounds.right()),
+                0.0,
+            )),
+            SliderOrientation::Vertical => rect.translate(Vec2::new(
+                0.0,
+                shift(rect.top(), rect.bottom(), bounds.top(), bounds.bottom()),
+            )),
+        }
+    }
+}
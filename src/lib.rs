@@ -1,3 +1,24 @@
 mod double_slider;
+mod error;
+mod gradient;
+mod handle;
+mod history;
+mod layout;
+mod mapping;
+mod marker;
+mod preset;
+mod range_text;
+mod style;
+mod track;
+mod value;
 
-pub use double_slider::DoubleSlider;
+pub use double_slider::{CollisionPolicy, DoubleSlider};
+pub use error::DoubleSliderError;
+pub use gradient::Gradient;
+pub use handle::{Handle, HandleShape, HandleState};
+pub use history::SliderHistory;
+pub use mapping::SliderMapping;
+pub use marker::Marker;
+pub use preset::PresetDisplay;
+pub use style::{DoubleSliderStyle, InteractionState, PartStyle, PartVisuals};
+pub use track::TrackCap;
@@ -0,0 +1,176 @@
+use egui::emath::{Pos2, Rect};
+use egui::{Context, Id, SliderOrientation};
+use std::ops::RangeInclusive;
+
+/// Conversion between values, normalized positions and screen coordinates of a slider.
+///
+/// Normalized positions go from 0 at the start to 1 at the end of the range, in the scale of the slider.
+/// Positions are screen coordinates along the slider, i.e. `x` for a horizontal and `y` for a vertical slider.
+/// Values and positions outside of the range are clamped to its ends.
+///
+/// The mapping of a [`crate::DoubleSlider`] as it was last shown can be loaded with [`Self::load`],
+/// e.g. to paint overlays that are aligned with it:
+///
+/// ```
+/// use egui_double_slider::{DoubleSlider, SliderMapping};
+///
+/// egui::__run_test_ui(|ui| {
+///     let (mut low, mut high) = (20.0, 80.0);
+///     let response = ui.add(DoubleSlider::new(&mut low, &mut high, 0.0..=100.0));
+///     if let Some(mapping) = SliderMapping::load(ui.ctx(), response.id) {
+///         let marker = mapping.screen_from_value(50.0);
+///         ui.painter().circle_filled(marker, 2.0, egui::Color32::RED);
+///     }
+/// });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SliderMapping {
+    start: f64,
+    end: f64,
+    logarithmic: bool,
+    orientation: SliderOrientation,
+    rect: Rect,
+}
+
+impl SliderMapping {
+    /// A linear mapping of `range` onto `rect`, whose ends are the centers of the control points at the ends of the range.
+    /// The start of the range is on the left of a horizontal and at the bottom of a vertical slider.
+    pub fn new(range: RangeInclusive<f64>, rect: Rect, orientation: SliderOrientation) -> Self {
+        Self {
+            start: *range.start(),
+            end: *range.end(),
+            logarithmic: false,
+            orientation,
+            rect,
+        }
+    }
+
+    /// Use a logarithmic scale, which requires a range of strictly positive values.
+    /// Default is false.
+    #[inline]
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.logarithmic = logarithmic;
+        self
+    }
+
+    /// The mapping of the slider with the given id (see [`egui::Response::id`]), as it was last shown.
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data(|data| data.get_temp(Self::id(id)))
+    }
+
+    pub(crate) fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|data| data.insert_temp(Self::id(id), self));
+    }
+
+    fn id(slider_id: Id) -> Id {
+        slider_id.with("mapping")
+    }
+
+    /// The range of the slider, which can go from high to low.
+    pub fn range(&self) -> RangeInclusive<f64> {
+        self.start..=self.end
+    }
+
+    /// The slider uses a logarithmic scale.
+    pub fn is_logarithmic(&self) -> bool {
+        self.logarithmic
+    }
+
+    /// Orientation of the slider.
+    pub fn orientation(&self) -> SliderOrientation {
+        self.orientation
+    }
+
+    /// The area between the centers of the control points at the ends of the range.
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// The positions of the start and the end of the range.
+    pub fn position_range(&self) -> (f32, f32) {
+        match self.orientation {
+            SliderOrientation::Horizontal => (self.rect.left(), self.rect.right()),
+            SliderOrientation::Vertical => (self.rect.bottom(), self.rect.top()),
+        }
+    }
+
+    /// The normalized position of a value.
+    pub fn normalized_from_value(&self, value: f64) -> f64 {
+        let (mut value, mut start, mut end) = (value, self.start, self.end);
+        if self.logarithmic {
+            if value <= 0.0 || start <= 0.0 || end <= 0.0 {
+                // there is no logarithm, map it to the start
+                return 0.0;
+            }
+            (value, start, end) = (value.log10(), start.log10(), end.log10());
+        }
+        if start == end {
+            // a range of a single value is mapped to the start
+            0.0
+        } else {
+            ((value - start) / (end - start)).clamp(0.0, 1.0)
+        }
+    }
+
+    /// The value at a normalized position.
+    pub fn value_from_normalized(&self, normalized: f64) -> f64 {
+        let normalized = normalized.clamp(0.0, 1.0);
+        let (start, end) = (self.start, self.end);
+        if start == end {
+            start
+        } else if self.logarithmic {
+            if start <= 0.0 || end <= 0.0 {
+                // there is no logarithm, fall back to the start
+                return start;
+            }
+            let (log_start, log_end) = (start.log10(), end.log10());
+            10.0f64.powf(log_start + (log_end - log_start) * normalized)
+        } else {
+            start + (end - start) * normalized
+        }
+    }
+
+    /// The position along the slider of a normalized position.
+    pub fn position_from_normalized(&self, normalized: f64) -> f32 {
+        let (start, end) = self.position_range();
+        start + (end - start) * normalized.clamp(0.0, 1.0) as f32
+    }
+
+    /// The normalized position of a position along the slider.
+    pub fn normalized_from_position(&self, position: f32) -> f64 {
+        let (start, end) = self.position_range();
+        if start == end {
+            // all positions are at the start if the slider has no size
+            0.0
+        } else {
+            ((position - start) as f64 / (end - start) as f64).clamp(0.0, 1.0)
+        }
+    }
+
+    /// The position along the slider of a value.
+    pub fn position_from_value(&self, value: f64) -> f32 {
+        self.position_from_normalized(self.normalized_from_value(value))
+    }
+
+    /// The value at a position along the slider.
+    pub fn value_from_position(&self, position: f32) -> f64 {
+        self.value_from_normalized(self.normalized_from_position(position))
+    }
+
+    /// The point of a value on the center line of the slider.
+    pub fn screen_from_value(&self, value: f64) -> Pos2 {
+        let position = self.position_from_value(value);
+        match self.orientation {
+            SliderOrientation::Horizontal => Pos2::new(position, self.rect.center().y),
+            SliderOrientation::Vertical => Pos2::new(self.rect.center().x, position),
+        }
+    }
+
+    /// The value at the point along the slider that is nearest to a screen point.
+    pub fn value_from_screen(&self, point: Pos2) -> f64 {
+        match self.orientation {
+            SliderOrientation::Horizontal => self.value_from_position(point.x),
+            SliderOrientation::Vertical => self.value_from_position(point.y),
+        }
+    }
+}
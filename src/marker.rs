@@ -0,0 +1,56 @@
+use egui::emath::Numeric;
+use egui::epaint::Color32;
+
+/// A reference value marked on the line of a [`crate::DoubleSlider`], e.g. a playback position or a threshold,
+/// see [`crate::DoubleSlider::marker`].
+///
+/// It is drawn as a line across the slider with an optional label, above a horizontal and to the right of a vertical slider
+/// (beyond the value labels), and shows a tooltip while hovered.
+/// Markers outside of the range are not shown.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Marker<T: Numeric> {
+    pub(crate) value: T,
+    pub(crate) label: String,
+    pub(crate) tooltip: Option<String>,
+    pub(crate) color: Option<Color32>,
+}
+
+impl<T: Numeric> Marker<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            label: String::new(),
+            tooltip: None,
+            color: None,
+        }
+    }
+
+    /// Set the label shown next to the marker.
+    /// Default is no label.
+    #[inline]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Set the text shown while the marker is hovered.
+    /// Default is the label and the value.
+    #[inline]
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Set the color of the marker and its label.
+    /// Default is the text color of the [`egui::Visuals`].
+    #[inline]
+    pub fn color(mut self, color: Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// The value of the marker.
+    pub fn value(&self) -> T {
+        self.value
+    }
+}
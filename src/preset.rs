@@ -0,0 +1,53 @@
+use egui::{Context, Id};
+
+/// How the presets of a [`crate::DoubleSlider`] are shown, see [`crate::DoubleSlider::preset`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PresetDisplay {
+    /// A chip for each preset, below a horizontal and to the right of a vertical slider.
+    /// The chip of the preset that is selected is highlighted.
+    #[default]
+    Chips,
+
+    /// A dropdown below a horizontal and to the right of a vertical slider,
+    /// showing the name of the preset that is selected.
+    Dropdown,
+}
+
+/// A running transition of the values to a preset, see [`crate::DoubleSlider::animate_presets`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PresetAnimation {
+    /// Index of the preset that the values move to.
+    pub(crate) preset: usize,
+
+    /// The values when the preset was selected.
+    pub(crate) from: (f64, f64),
+
+    pub(crate) start_time: f64,
+}
+
+impl PresetAnimation {
+    pub(crate) fn load(ctx: &Context, slider_id: Id) -> Option<Self> {
+        ctx.data(|data| data.get_temp(Self::id(slider_id)))
+    }
+
+    pub(crate) fn store(self, ctx: &Context, slider_id: Id) {
+        ctx.data_mut(|data| data.insert_temp(Self::id(slider_id), self));
+    }
+
+    pub(crate) fn clear(ctx: &Context, slider_id: Id) {
+        ctx.data_mut(|data| data.remove::<Self>(Self::id(slider_id)));
+    }
+
+    fn id(slider_id: Id) -> Id {
+        slider_id.with("preset animation")
+    }
+
+    /// How far the transition has gone at `time`, from 0 to 1 and eased at both ends.
+    pub(crate) fn progress(&self, time: f64, duration: f32) -> f64 {
+        if duration <= 0.0 {
+            return 1.0;
+        }
+        let t = ((time - self.start_time) / duration as f64).clamp(0.0, 1.0);
+        egui::emath::easing::cubic_in_out(t as f32) as f64
+    }
+}
@@ -0,0 +1,66 @@
+use egui::emath::Numeric;
+use std::ops::RangeInclusive;
+
+use crate::value;
+
+/// Formats a value with the shortest representation that converts back to the same value,
+/// e.g. `0.1` instead of `0.10000000149011612` for an `f32`.
+pub(crate) fn format_value<T: Numeric>(value: T) -> String {
+    if let Some(int) = value::to_i128(value) {
+        return int.to_string();
+    }
+    let float = value.to_f64();
+    if T::INTEGRAL {
+        return format!("{float}");
+    }
+    for digits in 0..17 {
+        if let Ok(rounded) = format!("{float:.digits$e}").parse::<f64>() {
+            if T::from_f64(rounded) == value {
+                return format!("{rounded:?}");
+            }
+        }
+    }
+    format!("{float:?}")
+}
+
+/// Formats a selection as `low..=high`.
+pub(crate) fn format_range<T: Numeric>(low: T, high: T) -> String {
+    format!("{}..={}", format_value(low), format_value(high))
+}
+
+/// Parses a selection written as `low..=high` or `[low, high]`.
+pub(crate) fn parse_range<T: Numeric>(text: &str) -> Option<(T, T)> {
+    let text = text.trim();
+    let (low, high) = match text
+        .strip_prefix('[')
+        .and_then(|text| text.strip_suffix(']'))
+    {
+        Some(list) => list.split_once(',')?,
+        None => text.split_once("..=")?,
+    };
+    Some((value::parse(low.trim())?, value::parse(high.trim())?))
+}
+
+/// Formats a value for a label next to the slider,
+/// with as many digits as needed to tell apart values that differ by `resolution`, within the range of `decimals`.
+pub(crate) fn format_label<T: Numeric>(
+    value: T,
+    resolution: f64,
+    decimals: RangeInclusive<usize>,
+) -> String {
+    if let Some(int) = value::to_i128(value) {
+        return int.to_string();
+    }
+    let value = value.to_f64();
+    if T::INTEGRAL {
+        return format!("{}", value.round());
+    }
+    let magnitude = value.abs();
+    if magnitude != 0.0 && !(1e-4..1e6).contains(&magnitude) {
+        let digits = (magnitude.log10().floor() - resolution.log10().floor()).clamp(0.0, 15.0);
+        return format!("{value:.*e}", digits as usize);
+    }
+    let needed = (-resolution.log10()).ceil().clamp(0.0, 15.0) as usize;
+    let (min, max) = (*decimals.start(), *decimals.end());
+    egui::emath::format_with_decimals_in_range(value, min..=needed.clamp(min, max))
+}
@@ -0,0 +1,214 @@
+use egui::epaint::{Color32, Stroke};
+use egui::{Context, Id, Response, Visuals};
+
+/// Interaction state of a part of the slider.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum InteractionState {
+    /// Not interacted with.
+    #[default]
+    Inactive,
+
+    /// The pointer is over the part.
+    Hovered,
+
+    /// The part is pressed or dragged.
+    Active,
+
+    /// The slider has keyboard focus, e.g. after clicking it or with `Tab`.
+    /// All parts are shown as focused unless they are active.
+    Focused,
+
+    /// The slider is disabled (see [`egui::Ui::add_enabled`]).
+    Disabled,
+}
+
+impl InteractionState {
+    /// The state of a part of the slider, given its response.
+    pub fn from_response(response: &Response) -> Self {
+        if !response.enabled() {
+            Self::Disabled
+        } else if response.is_pointer_button_down_on() || response.dragged() {
+            Self::Active
+        } else if response.has_focus() {
+            Self::Focused
+        } else if response.hovered() {
+            Self::Hovered
+        } else {
+            Self::Inactive
+        }
+    }
+
+    // The state of a part of a slider that has keyboard focus on any of its parts,
+    // as the part itself may not be the focused widget
+    pub(crate) fn with_focus(self, has_focus: bool) -> Self {
+        match self {
+            Self::Inactive | Self::Hovered if has_focus => Self::Focused,
+            state => state,
+        }
+    }
+}
+
+/// Fill and stroke of a part of the slider.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PartVisuals {
+    /// The color the part is filled with.
+    pub fill: Color32,
+
+    /// The outline of the part, [`Stroke::NONE`] for none.
+    pub stroke: Stroke,
+}
+
+impl PartVisuals {
+    pub fn new(fill: impl Into<Color32>, stroke: impl Into<Stroke>) -> Self {
+        Self {
+            fill: fill.into(),
+            stroke: stroke.into(),
+        }
+    }
+}
+
+/// Visuals of a part of the slider for each [`InteractionState`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PartStyle {
+    /// See [`InteractionState::Inactive`].
+    pub inactive: PartVisuals,
+
+    /// See [`InteractionState::Hovered`].
+    pub hovered: PartVisuals,
+
+    /// See [`InteractionState::Active`].
+    pub active: PartVisuals,
+
+    /// See [`InteractionState::Focused`].
+    pub focused: PartVisuals,
+
+    /// See [`InteractionState::Disabled`].
+    pub disabled: PartVisuals,
+}
+
+impl PartStyle {
+    /// The same visuals for every state.
+    pub fn uniform(visuals: PartVisuals) -> Self {
+        Self {
+            inactive: visuals,
+            hovered: visuals,
+            active: visuals,
+            focused: visuals,
+            disabled: visuals,
+        }
+    }
+
+    /// The visuals for the given state.
+    pub fn visuals(&self, state: InteractionState) -> PartVisuals {
+        match state {
+            InteractionState::Inactive => self.inactive,
+            InteractionState::Hovered => self.hovered,
+            InteractionState::Active => self.active,
+            InteractionState::Focused => self.focused,
+            InteractionState::Disabled => self.disabled,
+        }
+    }
+
+    /// The width of the widest stroke of all states.
+    pub(crate) fn max_stroke_width(&self) -> f32 {
+        [
+            self.inactive,
+            self.hovered,
+            self.active,
+            self.focused,
+            self.disabled,
+        ]
+        .iter()
+        .map(|visuals| visuals.stroke.width)
+        .fold(0.0, f32::max)
+    }
+
+    /// Set the fill of every state except [`InteractionState::Disabled`].
+    pub fn set_fill(&mut self, fill: Color32) {
+        for visuals in [
+            &mut self.inactive,
+            &mut self.hovered,
+            &mut self.active,
+            &mut self.focused,
+        ] {
+            visuals.fill = fill;
+        }
+    }
+}
+
+/// Colors and strokes of a [`crate::DoubleSlider`].
+///
+/// Set it for a single slider with [`crate::DoubleSlider::style`], or for all sliders with [`Self::set_global`].
+/// By default, it is derived from the active [`Visuals`] with [`Self::from_visuals`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DoubleSliderStyle {
+    /// The line along the whole slider.
+    pub track: PartStyle,
+
+    /// The highlighted part, between the control points (or outside of them with inverted highlighting).
+    pub band: PartStyle,
+
+    /// The control point of the lower value.
+    pub lower_handle: PartStyle,
+
+    /// The control point of the upper value.
+    pub upper_handle: PartStyle,
+}
+
+impl DoubleSliderStyle {
+    /// The default style, matching [`egui::Slider`].
+    pub fn from_visuals(visuals: &Visuals) -> Self {
+        let widgets = &visuals.widgets;
+        let half_width = |stroke: Stroke| Stroke::new(stroke.width / 2.0, stroke.color);
+
+        let track = PartStyle {
+            focused: PartVisuals::new(widgets.inactive.bg_fill, visuals.selection.stroke),
+            disabled: PartVisuals::new(widgets.noninteractive.bg_fill, Stroke::NONE),
+            ..PartStyle::uniform(PartVisuals::new(widgets.inactive.bg_fill, Stroke::NONE))
+        };
+        let band_fill = visuals.selection.bg_fill;
+        let band = PartStyle {
+            inactive: PartVisuals::new(band_fill, Stroke::new(1.0, band_fill)),
+            hovered: PartVisuals::new(band_fill, half_width(widgets.hovered.fg_stroke)),
+            active: PartVisuals::new(band_fill, half_width(widgets.active.fg_stroke)),
+            focused: PartVisuals::new(band_fill, half_width(widgets.active.fg_stroke)),
+            disabled: PartVisuals::new(widgets.noninteractive.bg_stroke.color, Stroke::NONE),
+        };
+        let handle = PartStyle {
+            inactive: PartVisuals::new(widgets.inactive.bg_fill, widgets.inactive.fg_stroke),
+            hovered: PartVisuals::new(widgets.hovered.bg_fill, widgets.hovered.fg_stroke),
+            active: PartVisuals::new(widgets.active.bg_fill, widgets.active.fg_stroke),
+            focused: PartVisuals::new(widgets.active.bg_fill, widgets.active.fg_stroke),
+            disabled: PartVisuals::new(
+                widgets.noninteractive.bg_fill,
+                widgets.noninteractive.fg_stroke,
+            ),
+        };
+
+        Self {
+            track,
+            band,
+            lower_handle: handle,
+            upper_handle: handle,
+        }
+    }
+
+    fn global_id() -> Id {
+        Id::new("egui_double_slider::DoubleSliderStyle")
+    }
+
+    /// The style set with [`Self::set_global`], if any.
+    pub fn global(ctx: &Context) -> Option<Self> {
+        ctx.data(|data| data.get_temp(Self::global_id()))
+    }
+
+    /// Use this style for all sliders without their own [`crate::DoubleSlider::style`].
+    pub fn set_global(ctx: &Context, style: Self) {
+        ctx.data_mut(|data| data.insert_temp(Self::global_id(), style));
+    }
+
+    /// Go back to deriving the style from the active [`Visuals`].
+    pub fn clear_global(ctx: &Context) {
+        ctx.data_mut(|data| data.remove::<Self>(Self::global_id()));
+    }
+}
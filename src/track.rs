@@ -0,0 +1,125 @@
+use egui::emath::{Pos2, Rect};
+use egui::SliderOrientation;
+
+/// Where the line of the slider ends, see [`crate::DoubleSlider::track_cap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TrackCap {
+    /// The line reaches the edges of the slider, below the control points at the ends of the range,
+    /// the same as [`egui::Slider`].
+    #[default]
+    Extended,
+
+    /// The line ends at the centers of the control points at the ends of the range.
+    Flush,
+}
+
+/// Layout of the line and the highlighted parts of the slider on the screen.
+///
+/// Positions along the slider are screen coordinates, i.e. `x` for a horizontal and `y` for a vertical slider.
+pub(crate) struct TrackLayout {
+    /// The whole line.
+    pub(crate) rect: Rect,
+
+    /// Corner radius of the line and all its parts, at most half of the thickness.
+    pub(crate) corner_radius: f32,
+
+    orientation: SliderOrientation,
+}
+
+impl TrackLayout {
+    /// `rect` is the area of the slider,
+    /// `handle_offset` is the distance of the control points at the ends of the range from its edges.
+    pub(crate) fn new(
+        rect: Rect,
+        orientation: SliderOrientation,
+        thickness: f32,
+        corner_radius: f32,
+        cap: TrackCap,
+        handle_offset: f32,
+    ) -> Self {
+        let thickness = thickness.max(0.0);
+        let inset = match cap {
+            TrackCap::Extended => 0.0,
+            TrackCap::Flush => handle_offset,
+        };
+        let center = rect.center();
+        let rect = match orientation {
+            SliderOrientation::Horizontal => Rect::from_x_y_ranges(
+                rect.x_range().shrink(inset),
+                center.y - thickness / 2.0..=center.y + thickness / 2.0,
+            ),
+            SliderOrientation::Vertical => Rect::from_x_y_ranges(
+                center.x - thickness / 2.0..=center.x + thickness / 2.0,
+                rect.y_range().shrink(inset),
+            ),
+        };
+        Self {
+            rect,
+            corner_radius: corner_radius.clamp(0.0, thickness / 2.0),
+            orientation,
+        }
+    }
+
+    /// Position of the end of the line at the start of the range (the bottom of a vertical slider).
+    pub(crate) fn start(&self) -> f32 {
+        match self.orientation {
+            SliderOrientation::Horizontal => self.rect.left(),
+            SliderOrientation::Vertical => self.rect.bottom(),
+        }
+    }
+
+    /// Position of the end of the line at the end of the range (the top of a vertical slider).
+    pub(crate) fn end(&self) -> f32 {
+        match self.orientation {
+            SliderOrientation::Horizontal => self.rect.right(),
+            SliderOrientation::Vertical => self.rect.top(),
+        }
+    }
+
+    /// Position of a point along the slider.
+    pub(crate) fn along(&self, pos: Pos2) -> f32 {
+        match self.orientation {
+            SliderOrientation::Horizontal => pos.x,
+            SliderOrientation::Vertical => pos.y,
+        }
+    }
+
+    /// The part of the line between two positions along the slider.
+    ///
+    /// Ends at control points are extended below them by the corner radius,
+    /// so that the visible part ends flat at the center of the control point like the trailing fill of [`egui::Slider`].
+    pub(crate) fn segment(&self, from: f32, to: f32, from_handle: bool, to_handle: bool) -> Rect {
+        let extension = if to >= from {
+            self.corner_radius
+        } else {
+            -self.corner_radius
+        };
+        let from = if from_handle { from - extension } else { from };
+        let to = if to_handle { to + extension } else { to };
+        let (min, max) = (from.min(to), from.max(to));
+        match self.orientation {
+            SliderOrientation::Horizontal => Rect::from_x_y_ranges(
+                min.max(self.rect.left())..=max.min(self.rect.right()),
+                self.rect.y_range(),
+            ),
+            SliderOrientation::Vertical => Rect::from_x_y_ranges(
+                self.rect.x_range(),
+                min.max(self.rect.top())..=max.min(self.rect.bottom()),
+            ),
+        }
+    }
+
+    /// A line across the line at a position along the slider, reaching `overhang` beyond both of its sides.
+    pub(crate) fn across(&self, pos: f32, overhang: f32) -> [Pos2; 2] {
+        match self.orientation {
+            SliderOrientation::Horizontal => [
+                Pos2::new(pos, self.rect.top() - overhang),
+                Pos2::new(pos, self.rect.bottom() + overhang),
+            ],
+            SliderOrientation::Vertical => [
+                Pos2::new(self.rect.left() - overhang, pos),
+                Pos2::new(self.rect.right() + overhang, pos),
+            ],
+        }
+    }
+}
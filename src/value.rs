@@ -0,0 +1,93 @@
+use egui::emath::Numeric;
+use std::any::Any;
+
+// Arithmetic on slider values of any numeric type.
+//
+// Values of 64-bit integer types (`i64`, `u64`, `isize` and `usize`) do not all fit into an `f64`,
+// e.g. nanosecond timestamps. They are calculated exactly as `i128`, so that they only lose precision
+// in differences beyond 2^53, which are far below the resolution of a slider.
+
+// Converts between types that are known to be the same, e.g. `T` and `u64` after checking the type
+fn cast<T: 'static, U: 'static>(value: U) -> Option<T> {
+    (&mut Some(value) as &mut dyn Any)
+        .downcast_mut::<Option<T>>()
+        .and_then(Option::take)
+}
+
+/// The exact value of 64-bit integer types, `None` for all other types.
+pub(crate) fn to_i128<T: Numeric>(value: T) -> Option<i128> {
+    cast::<i64, T>(value)
+        .map(i128::from)
+        .or_else(|| cast::<u64, T>(value).map(i128::from))
+        .or_else(|| cast::<isize, T>(value).map(|value| value as i128))
+        .or_else(|| cast::<usize, T>(value).map(|value| value as i128))
+}
+
+// The value of a 64-bit integer type, saturating at its limits
+pub(crate) fn from_i128<T: Numeric>(value: i128) -> Option<T> {
+    let value = value.clamp(to_i128(T::MIN)?, to_i128(T::MAX)?);
+    cast::<T, i64>(value as i64)
+        .or_else(|| cast(value as u64))
+        .or_else(|| cast(value as isize))
+        .or_else(|| cast(value as usize))
+}
+
+/// `to - from`.
+pub(crate) fn difference<T: Numeric>(from: T, to: T) -> f64 {
+    match (to_i128(from), to_i128(to)) {
+        (Some(from), Some(to)) => (to - from) as f64,
+        _ => to.to_f64() - from.to_f64(),
+    }
+}
+
+/// `value + delta`, rounded for integer types.
+pub(crate) fn offset<T: Numeric>(value: T, delta: f64) -> T {
+    if let Some(int) = to_i128(value) {
+        // the conversion saturates and maps NaN to 0
+        if let Some(value) = from_i128(int.saturating_add(delta.round() as i128)) {
+            return value;
+        }
+    }
+    let value = value.to_f64() + delta;
+    T::from_f64(if T::INTEGRAL { value.round() } else { value })
+}
+
+/// The value at `t` (from 0 to 1) of the way from `start` to `end`, which are hit exactly.
+pub(crate) fn lerp<T: Numeric>(start: T, end: T, t: f64) -> T {
+    if t >= 1.0 {
+        end
+    } else {
+        offset(start, difference(start, end) * t.max(0.0))
+    }
+}
+
+/// Parses a value, exactly for 64-bit integer types.
+pub(crate) fn parse<T: Numeric>(text: &str) -> Option<T> {
+    if to_i128(T::MIN).is_some() {
+        if let Ok(int) = text.parse::<i128>() {
+            return (to_i128(T::MIN)? <= int && int <= to_i128(T::MAX)?)
+                .then(|| from_i128(int))
+                .flatten();
+        }
+    }
+    let float = text.parse::<f64>().ok().filter(|float| float.is_finite())?;
+    Some(T::from_f64(if T::INTEGRAL { float.round() } else { float }))
+}
+
+/// Rounds to a number of decimals, which rounds to tens, hundreds, ... if it is negative.
+pub(crate) fn round_to_decimals(value: f64, decimals: i32) -> f64 {
+    if decimals >= 0 {
+        egui::emath::round_to_decimals(value, decimals as usize)
+    } else {
+        let factor = 10f64.powi(-decimals);
+        (value / factor).round() * factor
+    }
+}
+
+/// The number of decimals that keeps `digits` significant digits of a value.
+pub(crate) fn significant_decimals(value: f64, digits: usize) -> i32 {
+    if value == 0.0 || !value.is_finite() {
+        return 0;
+    }
+    digits as i32 - 1 - value.abs().log10().floor() as i32
+}
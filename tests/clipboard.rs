@@ -0,0 +1,76 @@
+//! Tests of copying and pasting the selection while the slider has keyboard focus.
+
+mod common;
+
+use common::{Harness, CONFIGS};
+use egui::{Event, FullOutput, OutputCommand};
+
+fn copied(output: &FullOutput) -> Option<String> {
+    output
+        .platform_output
+        .commands
+        .iter()
+        .find_map(|command| match command {
+            OutputCommand::CopyText(text) => Some(text.clone()),
+            _ => None,
+        })
+}
+
+// focuses the slider by clicking between the control points
+fn focus(harness: &mut Harness<f64>) {
+    harness.click(harness.point(50.0));
+}
+
+#[test]
+fn the_selection_is_copied_and_pasted() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f64>::window(config);
+        focus(&mut harness);
+        let output = harness.frame(vec![Event::Copy]);
+        assert_eq!(
+            copied(&output).as_deref(),
+            Some("20.0..=80.0"),
+            "{config:?}"
+        );
+
+        harness.frame(vec![Event::Paste("30..=40".to_owned())]);
+        assert_eq!(harness.values(), (30.0, 40.0), "{config:?}");
+        harness.frame(vec![Event::Paste("[ 5.5, 60 ]".to_owned())]);
+        assert_eq!(harness.values(), (5.5, 60.0), "{config:?}");
+    }
+}
+
+#[test]
+fn invalid_selections_are_ignored() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f64>::window(config).options(|slider| slider.max_span(70.0));
+        focus(&mut harness);
+        for text in [
+            "a range",
+            "0.5..=50",
+            "50..=101",
+            "40..=30",
+            "50..=50.5",
+            "10..=90",
+        ] {
+            harness.frame(vec![Event::Paste(text.to_owned())]);
+            assert_eq!(harness.values(), (20.0, 80.0), "{text} {config:?}");
+        }
+    }
+}
+
+#[test]
+fn the_clipboard_needs_focus_and_can_be_disabled() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f64>::window(config);
+        let output = harness.frame(vec![Event::Copy, Event::Paste("30..=40".to_owned())]);
+        assert_eq!(copied(&output), None, "{config:?}");
+        assert_eq!(harness.values(), (20.0, 80.0), "{config:?}");
+
+        let mut harness = Harness::<f64>::window(config).options(|slider| slider.clipboard(false));
+        focus(&mut harness);
+        let output = harness.frame(vec![Event::Copy, Event::Paste("30..=40".to_owned())]);
+        assert_eq!(copied(&output), None, "{config:?}");
+        assert_eq!(harness.values(), (20.0, 80.0), "{config:?}");
+    }
+}
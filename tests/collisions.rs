@@ -0,0 +1,115 @@
+//! Tests of the distance between the values: the collision policies, the maximum span and overlapping control points.
+
+mod common;
+
+use common::{assert_near, Harness, CONFIGS};
+use egui_double_slider::CollisionPolicy;
+
+#[test]
+fn blocked_control_points_stop_at_the_separation_distance() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f64>::window(config)
+            .options(|slider| slider.collision_policy(CollisionPolicy::Block));
+        let from = harness.point(harness.low);
+        harness.drag(from, harness.point(90.0));
+        assert_eq!(harness.values(), (79.0, 80.0), "{config:?}");
+
+        let mut harness = Harness::<f64>::window(config)
+            .options(|slider| slider.collision_policy(CollisionPolicy::Block));
+        let from = harness.point(harness.high);
+        harness.drag(from, harness.point(10.0));
+        assert_eq!(harness.values(), (20.0, 21.0), "{config:?}");
+    }
+}
+
+#[test]
+fn swapped_control_points_exchange_their_values() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f64>::window(config)
+            .options(|slider| slider.collision_policy(CollisionPolicy::Swap));
+        let from = harness.point(harness.low);
+        let to = harness.point(90.0);
+        harness.drag(from, to);
+
+        // the dragged lower control point became the upper one
+        let (low, high) = harness.values();
+        assert_eq!(low, 80.0, "{config:?}");
+        assert_near(high, 90.0, harness.resolution(90.0), "upper value", config);
+
+        // the same for the upper control point
+        let mut harness = Harness::<f64>::window(config)
+            .options(|slider| slider.collision_policy(CollisionPolicy::Swap));
+        let from = harness.point(harness.high);
+        harness.drag(from, harness.point(10.0));
+        let (low, high) = harness.values();
+        assert_near(low, 10.0, harness.resolution(10.0), "lower value", config);
+        assert_eq!(high, 20.0, "{config:?}");
+    }
+}
+
+#[test]
+fn the_maximum_span_pushes_or_blocks() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f64>::new(10.0, 50.0, 1.0..=1000.0, config)
+            .options(|slider| slider.max_span(60.0));
+        let from = harness.point(harness.high);
+        harness.drag(from, harness.point(200.0));
+        let (low, high) = harness.values();
+        assert_near(
+            high,
+            200.0,
+            harness.resolution(200.0),
+            "upper value",
+            config,
+        );
+        assert_near(high - low, 60.0, 1e-9, "pushed span", config);
+
+        let mut harness = Harness::<f64>::new(10.0, 50.0, 1.0..=1000.0, config).options(|slider| {
+            slider
+                .max_span(60.0)
+                .collision_policy(CollisionPolicy::Block)
+        });
+        let from = harness.point(harness.high);
+        harness.drag(from, harness.point(200.0));
+        assert_eq!(harness.values(), (10.0, 70.0), "{config:?}");
+    }
+}
+
+#[test]
+fn zooming_out_stops_at_the_maximum_span() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f64>::window(config).options(|slider| slider.max_span(70.0));
+        for _ in 0..10 {
+            harness.zoom(2.0);
+        }
+        let (low, high) = harness.values();
+        assert_near(high - low, 70.0, 1e-9, "span", config);
+    }
+}
+
+#[test]
+fn overlapping_control_points_follow_the_drag_direction() {
+    for config in CONFIGS {
+        let overlapping = || {
+            let mut harness =
+                Harness::<f64>::window(config).options(|slider| slider.separation_distance(0.0));
+            (harness.low, harness.high) = (50.0, 50.0);
+            harness.frame(Vec::new());
+            harness
+        };
+
+        let mut harness = overlapping();
+        let from = harness.point(50.0);
+        harness.drag(from, harness.along(from, 20.0));
+        let (low, high) = harness.values();
+        assert_eq!(low, 50.0, "{config:?}");
+        assert!(high > 50.0, "{high} {config:?}");
+
+        let mut harness = overlapping();
+        let from = harness.point(50.0);
+        harness.drag(from, harness.along(from, -20.0));
+        let (low, high) = harness.values();
+        assert!(low < 50.0, "{low} {config:?}");
+        assert_eq!(high, 50.0, "{config:?}");
+    }
+}
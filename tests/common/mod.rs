@@ -0,0 +1,311 @@
+//! Headless driver for [`DoubleSlider`], simulating input events on an [`egui::Context`].
+
+#![allow(dead_code)]
+
+use egui::emath::Numeric;
+use egui::epaint::{ClippedShape, Shape};
+use egui::{
+    Context, Event, FontDefinitions, FullOutput, Id, Key, Modifiers, MouseWheelUnit, PointerButton,
+    Pos2, RawInput, Rect, SliderOrientation, TouchPhase, Vec2,
+};
+use egui_double_slider::{DoubleSlider, SliderMapping};
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+const SCREEN: Rect = Rect::from_min_max(Pos2::ZERO, Pos2::new(400.0, 300.0));
+
+// number of frames to move the pointer from the start to the end of a drag
+const DRAG_STEPS: usize = 10;
+
+// number of frames until smooth scrolling has settled
+const SCROLL_FRAMES: usize = 60;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    pub orientation: SliderOrientation,
+    pub logarithmic: bool,
+}
+
+pub const CONFIGS: [Config; 4] = [
+    Config {
+        orientation: SliderOrientation::Horizontal,
+        logarithmic: false,
+    },
+    Config {
+        orientation: SliderOrientation::Horizontal,
+        logarithmic: true,
+    },
+    Config {
+        orientation: SliderOrientation::Vertical,
+        logarithmic: false,
+    },
+    Config {
+        orientation: SliderOrientation::Vertical,
+        logarithmic: true,
+    },
+];
+
+/// Builder calls applied to the slider in every frame.
+pub type Options<T> = dyn for<'a> Fn(DoubleSlider<'a, T>) -> DoubleSlider<'a, T>;
+
+/// Shows one slider per frame on a headless [`Context`] and keeps its values between frames.
+pub struct Harness<T: Numeric> {
+    ctx: Context,
+    time: f64,
+    pub low: T,
+    pub high: T,
+    /// Modifier keys held down in every frame.
+    pub modifiers: Modifiers,
+    /// Whether the slider is added enabled, see [`egui::Ui::add_enabled`].
+    pub enabled: bool,
+    /// Whether the slider reported a change in the last frame, see [`egui::Response::changed`].
+    pub changed: bool,
+    range: RangeInclusive<T>,
+    config: Config,
+    options: Box<Options<T>>,
+    id: Id,
+    rect: Rect,
+}
+
+impl<T: Numeric + Debug> Harness<T> {
+    pub fn new(low: f64, high: f64, range: RangeInclusive<f64>, config: Config) -> Self {
+        let range = T::from_f64(*range.start())..=T::from_f64(*range.end());
+        Self::with_values(T::from_f64(low), T::from_f64(high), range, config)
+    }
+
+    /// A slider at `20..=80` in the range `1..=100`, which is valid for all configs.
+    pub fn window(config: Config) -> Self {
+        Self::new(20.0, 80.0, 1.0..=100.0, config)
+    }
+
+    pub fn with_values(low: T, high: T, range: RangeInclusive<T>, config: Config) -> Self {
+        let ctx = Context::default();
+        ctx.set_fonts(FontDefinitions::empty());
+        let mut harness = Self {
+            ctx,
+            time: 0.0,
+            low,
+            high,
+            modifiers: Modifiers::NONE,
+            enabled: true,
+            changed: false,
+            range,
+            config,
+            options: Box::new(|slider| slider),
+            id: Id::NULL,
+            rect: Rect::NOTHING,
+        };
+        // the first frame lays out the slider
+        harness.frame(Vec::new());
+        harness
+    }
+
+    pub fn options(
+        mut self,
+        options: impl for<'a> Fn(DoubleSlider<'a, T>) -> DoubleSlider<'a, T> + 'static,
+    ) -> Self {
+        self.options = Box::new(options);
+        self.frame(Vec::new());
+        self
+    }
+
+    pub fn frame(&mut self, events: Vec<Event>) -> FullOutput {
+        self.time += 1.0 / 60.0;
+        let input = RawInput {
+            screen_rect: Some(SCREEN),
+            time: Some(self.time),
+            predicted_dt: 1.0 / 60.0,
+            modifiers: self.modifiers,
+            events,
+            ..Default::default()
+        };
+        let (low, high) = (&mut self.low, &mut self.high);
+        let (range, config, options) = (&self.range, self.config, &self.options);
+        let enabled = self.enabled;
+        let (mut id, mut rect, mut changed) = (self.id, self.rect, false);
+        let output = self.ctx.run_ui(input, |ui| {
+            let slider = DoubleSlider::new(low, high, range.clone())
+                .orientation(config.orientation)
+                .logarithmic(config.logarithmic);
+            let response = ui.add_enabled(enabled, options(slider));
+            (id, rect, changed) = (response.id, response.rect, response.changed());
+        });
+        (self.id, self.rect, self.changed) = (id, rect, changed);
+        output
+    }
+
+    /// The rect of the slider, see [`egui::Response::rect`].
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    pub fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+
+    /// The id of the slider, see [`egui::Response::id`].
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    pub fn mapping(&self) -> SliderMapping {
+        SliderMapping::load(&self.ctx, self.id).expect("the slider was shown")
+    }
+
+    pub fn values(&self) -> (f64, f64) {
+        (self.low.to_f64(), self.high.to_f64())
+    }
+
+    pub fn point(&self, value: T) -> Pos2 {
+        self.mapping().screen_from_value(value.to_f64())
+    }
+
+    /// A point `distance` along the slider from `point`, towards the end of the range.
+    pub fn along(&self, point: Pos2, distance: f32) -> Pos2 {
+        match self.config.orientation {
+            SliderOrientation::Horizontal => point + Vec2::new(distance, 0.0),
+            SliderOrientation::Vertical => point - Vec2::new(0.0, distance),
+        }
+    }
+
+    pub fn drag(&mut self, from: Pos2, to: Pos2) {
+        self.frame(vec![Event::PointerMoved(from)]);
+        self.frame(vec![button(from, true)]);
+        for step in 1..=DRAG_STEPS {
+            let pos = from.lerp(to, step as f32 / DRAG_STEPS as f32);
+            self.frame(vec![Event::PointerMoved(pos)]);
+        }
+        self.frame(vec![button(to, false)]);
+    }
+
+    pub fn click(&mut self, pos: Pos2) {
+        self.frame(vec![Event::PointerMoved(pos)]);
+        self.frame(vec![button(pos, true)]);
+        self.frame(vec![button(pos, false)]);
+    }
+
+    /// Clicks with the secondary button, e.g. to open the context menu.
+    pub fn secondary_click(&mut self, pos: Pos2) {
+        let event = |pressed| Event::PointerButton {
+            pos,
+            button: PointerButton::Secondary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        self.frame(vec![Event::PointerMoved(pos)]);
+        self.frame(vec![event(true)]);
+        self.frame(vec![event(false)]);
+    }
+
+    pub fn key(&mut self, key: Key, modifiers: Modifiers) {
+        let event = |pressed| Event::Key {
+            key,
+            physical_key: None,
+            pressed,
+            repeat: false,
+            modifiers,
+        };
+        self.frame(vec![event(true)]);
+        self.frame(vec![event(false)]);
+    }
+
+    pub fn scroll(&mut self, delta: Vec2) {
+        let center = self.rect.center();
+        self.frame(vec![
+            Event::PointerMoved(center),
+            Event::MouseWheel {
+                unit: MouseWheelUnit::Point,
+                delta,
+                phase: TouchPhase::Move,
+                modifiers: Modifiers::NONE,
+            },
+        ]);
+        for _ in 0..SCROLL_FRAMES {
+            self.frame(Vec::new());
+        }
+    }
+
+    pub fn zoom(&mut self, factor: f32) {
+        let center = self.rect.center();
+        self.frame(vec![Event::PointerMoved(center)]);
+        self.frame(vec![Event::Zoom(factor)]);
+    }
+
+    /// The largest value difference of one pixel around `value`.
+    pub fn resolution(&self, value: f64) -> f64 {
+        let mapping = self.mapping();
+        let pos = mapping.position_from_value(value);
+        let pixel = (mapping.value_from_position(pos + 1.0) - value)
+            .abs()
+            .max((value - mapping.value_from_position(pos - 1.0)).abs());
+        if T::INTEGRAL {
+            pixel.max(1.0)
+        } else {
+            pixel
+        }
+    }
+}
+
+pub fn button(pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton {
+        pos,
+        button: PointerButton::Primary,
+        pressed,
+        modifiers: Modifiers::NONE,
+    }
+}
+
+/// The texts drawn in a frame.
+pub fn texts(output: &FullOutput) -> Vec<String> {
+    output
+        .shapes
+        .iter()
+        .filter_map(|ClippedShape { shape, .. }| match shape {
+            Shape::Text(text) => Some(text.galley.text().to_owned()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The rect of the first text drawn in a frame that equals `text`, e.g. the label of a button.
+pub fn text_rect(output: &FullOutput, text: &str) -> Option<Rect> {
+    output
+        .shapes
+        .iter()
+        .find_map(|ClippedShape { shape, .. }| match shape {
+            Shape::Text(shape) if shape.galley.text() == text => {
+                Some(shape.galley.rect.translate(shape.pos.to_vec2()))
+            }
+            _ => None,
+        })
+}
+
+/// Whether a line is drawn across the slider at `pos` along it, e.g. a marker.
+pub fn line_at(output: &FullOutput, pos: f32, orientation: SliderOrientation) -> bool {
+    output
+        .shapes
+        .iter()
+        .any(|ClippedShape { shape, .. }| match shape {
+            Shape::LineSegment { points, .. } => points.iter().all(|point| {
+                let along = match orientation {
+                    SliderOrientation::Horizontal => point.x,
+                    SliderOrientation::Vertical => point.y,
+                };
+                (along - pos).abs() < 0.01
+            }),
+            _ => false,
+        })
+}
+
+pub fn for_all_configs(test: impl Fn(Config)) {
+    for config in CONFIGS {
+        test(config);
+    }
+}
+
+pub fn assert_near(actual: f64, expected: f64, tolerance: f64, what: &str, config: Config) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "{what}: {actual} is not within {tolerance} of {expected} ({config:?})"
+    );
+}
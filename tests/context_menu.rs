@@ -0,0 +1,150 @@
+//! Tests of the context menu, opened with a secondary click on the slider.
+
+mod common;
+
+use common::{text_rect, texts, Harness, CONFIGS};
+use egui::{Event, FullOutput, OutputCommand, Pos2, Rect, Vec2, ViewportCommand};
+use egui_double_slider::DoubleSlider;
+
+fn menu(slider: DoubleSlider<'_, f64>) -> DoubleSlider<'_, f64> {
+    slider
+        .reset_on_double_click(10.0, 90.0)
+        .context_menu_entries(|ui| {
+            ui.label("custom entry");
+        })
+}
+
+// opens the context menu and returns the rect of the entry with the given text
+fn open(harness: &mut Harness<f64>, entry: &str) -> Rect {
+    harness.secondary_click(harness.point(50.0));
+    let output = harness.frame(Vec::new());
+    text_rect(&output, entry).expect("the context menu shows the entry")
+}
+
+// clicks at `pos` and returns the output of the frame in which the button is released
+fn click(harness: &mut Harness<f64>, pos: Pos2) -> FullOutput {
+    harness.frame(vec![Event::PointerMoved(pos)]);
+    harness.frame(vec![common::button(pos, true)]);
+    harness.frame(vec![common::button(pos, false)])
+}
+
+#[test]
+fn paste_range_requests_the_clipboard() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(menu);
+        let paste = open(&mut harness, "Paste range");
+        let output = click(&mut harness, paste.center());
+        let requested = output
+            .viewport_output
+            .values()
+            .any(|viewport| viewport.commands.contains(&ViewportCommand::RequestPaste));
+        assert!(requested, "{config:?}");
+
+        // the integration answers with the text from the clipboard
+        harness.frame(vec![Event::Paste("30..=40".to_owned())]);
+        assert_eq!(harness.values(), (30.0, 40.0), "{config:?}");
+    }
+}
+
+#[test]
+fn reset_and_select_full_range() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(menu);
+        let reset = open(&mut harness, "Reset");
+        click(&mut harness, reset.center());
+        assert_eq!(harness.values(), (10.0, 90.0), "{config:?}");
+
+        let full_range = open(&mut harness, "Select full range");
+        click(&mut harness, full_range.center());
+        assert_eq!(harness.values(), (1.0, 100.0), "{config:?}");
+    }
+}
+
+#[test]
+fn copy_range() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(menu);
+        let copy = open(&mut harness, "Copy range");
+        let output = click(&mut harness, copy.center());
+        assert!(
+            output
+                .platform_output
+                .commands
+                .contains(&OutputCommand::CopyText("20.0..=80.0".to_owned())),
+            "{config:?}"
+        );
+    }
+}
+
+#[test]
+fn values_are_entered_exactly() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(menu);
+        let label = open(&mut harness, "Lower:");
+        let output = harness.frame(Vec::new());
+        let lower = text_rect(&output, "20").expect("the lower value is shown");
+        assert!(
+            (lower.center().y - label.center().y).abs() < 1.0,
+            "{config:?}"
+        );
+        harness.drag(lower.center(), lower.center() + Vec2::new(10.0, 0.0));
+        let (low, high) = harness.values();
+        assert!(low > 20.0, "{low} {config:?}");
+        assert_eq!(high, 80.0, "{config:?}");
+    }
+}
+
+#[test]
+fn the_scale_is_toggled() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(menu);
+        let logarithmic = open(&mut harness, "Logarithmic");
+        click(&mut harness, logarithmic.center());
+        harness.frame(Vec::new());
+        assert_eq!(
+            harness.mapping().is_logarithmic(),
+            !config.logarithmic,
+            "{config:?}"
+        );
+    }
+}
+
+#[test]
+fn custom_entries_are_added() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(menu);
+        open(&mut harness, "custom entry");
+
+        // without the context menu, a secondary click does nothing
+        let mut harness = Harness::<f64>::window(config);
+        harness.secondary_click(harness.point(50.0));
+        let output = harness.frame(Vec::new());
+        assert!(text_rect(&output, "Reset").is_none(), "{config:?}");
+        assert!(text_rect(&output, "Copy range").is_none(), "{config:?}");
+    }
+}
+
+#[test]
+fn the_ticks_follow_the_toggled_scale() {
+    for config in CONFIGS {
+        let mut harness =
+            Harness::new(20.0, 80.0, 1.0..=10000.0, config).options(|slider| menu(slider).ticks(5));
+        let logarithmic = open(&mut harness, "Logarithmic");
+        click(&mut harness, logarithmic.center());
+        let output = harness.frame(Vec::new());
+        let (present, absent) = if config.logarithmic {
+            ("2501", "1000")
+        } else {
+            ("1000", "2501")
+        };
+        let texts = texts(&output);
+        assert!(
+            texts.iter().any(|text| text == present),
+            "{texts:?} {config:?}"
+        );
+        assert!(
+            texts.iter().all(|text| text != absent),
+            "{texts:?} {config:?}"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Tests of invalid configurations of [`DoubleSlider`], which are reported instead of panicking.
+
+mod common;
+
+use common::{Harness, CONFIGS};
+use egui::epaint::{ClippedShape, Shape};
+use egui::Visuals;
+use egui_double_slider::{DoubleSlider, DoubleSliderError};
+
+#[test]
+fn invalid_configurations_are_reported() {
+    let (mut low, mut high) = (2.0, 8.0);
+    let mut check = |build: &dyn Fn(DoubleSlider<'_, f64>) -> DoubleSlider<'_, f64>,
+                     range: std::ops::RangeInclusive<f64>,
+                     expected: Result<(), DoubleSliderError>| {
+        let slider = build(DoubleSlider::new(&mut low, &mut high, range));
+        assert_eq!(slider.validate(), expected);
+        assert_eq!(slider.try_build().map(|_| ()), expected);
+    };
+
+    check(&|slider| slider, 0.0..=10.0, Ok(()));
+    check(
+        &|slider| slider,
+        0.0..=f64::INFINITY,
+        Err(DoubleSliderError::NonFiniteRange {
+            start: 0.0,
+            end: f64::INFINITY,
+        }),
+    );
+    check(
+        &|slider| slider.logarithmic(true),
+        0.0..=10.0,
+        Err(DoubleSliderError::LogarithmicRange {
+            start: 0.0,
+            end: 10.0,
+        }),
+    );
+    check(
+        &|slider| slider.separation_distance(-1.0),
+        0.0..=10.0,
+        Err(DoubleSliderError::InvalidSeparation(-1.0)),
+    );
+    check(
+        &|slider| slider.max_span(-2.0),
+        0.0..=10.0,
+        Err(DoubleSliderError::InvalidMaxSpan(-2.0)),
+    );
+    check(
+        &|slider| slider.separation_distance(3.0).max_span(2.0),
+        0.0..=10.0,
+        Err(DoubleSliderError::SeparationExceedsMaxSpan {
+            separation: 3.0,
+            max_span: 2.0,
+        }),
+    );
+    check(
+        &|slider| slider.separation_distance(2.0).max_span(2.0),
+        0.0..=10.0,
+        Ok(()),
+    );
+    check(
+        &|slider| slider.significant_digits(0),
+        0.0..=10.0,
+        Err(DoubleSliderError::InvalidSignificantDigits),
+    );
+    check(
+        &|slider| slider.size(0.0),
+        0.0..=10.0,
+        Err(DoubleSliderError::InvalidSize(0.0)),
+    );
+    check(
+        &|slider| slider.min_size(f32::INFINITY),
+        0.0..=10.0,
+        Err(DoubleSliderError::InvalidSize(f32::INFINITY)),
+    );
+    check(
+        &|slider| slider.control_point_radius(-3.0),
+        0.0..=10.0,
+        Err(DoubleSliderError::InvalidControlPointRadius(-3.0)),
+    );
+    check(
+        &|slider| slider.size(20.0).control_point_radius(15.0),
+        0.0..=10.0,
+        Err(DoubleSliderError::ControlPointTooLarge {
+            radius: 15.0,
+            size: 20.0,
+        }),
+    );
+}
+
+#[test]
+fn nan_range_is_reported() {
+    let (mut low, mut high) = (2.0, 8.0);
+    let slider = DoubleSlider::new(&mut low, &mut high, f64::NAN..=10.0);
+    assert!(matches!(
+        slider.validate(),
+        Err(DoubleSliderError::NonFiniteRange { start, end: 10.0 }) if start.is_nan()
+    ));
+}
+
+#[test]
+fn nan_distances_are_reported() {
+    let (mut low, mut high) = (2.0, 8.0);
+    let error = DoubleSlider::new(&mut low, &mut high, 0.0..=10.0)
+        .separation_distance(f64::NAN)
+        .validate()
+        .expect_err("NaN separation distance");
+    assert!(
+        matches!(error, DoubleSliderError::InvalidSeparation(separation) if separation.is_nan())
+    );
+    assert_eq!(
+        error.to_string(),
+        "the separation distance NaN is negative or NaN"
+    );
+
+    let error = DoubleSlider::new(&mut low, &mut high, 0.0..=10.0)
+        .max_span(f64::NAN)
+        .validate()
+        .expect_err("NaN maximum span");
+    assert!(matches!(error, DoubleSliderError::InvalidMaxSpan(max_span) if max_span.is_nan()));
+    assert_eq!(error.to_string(), "the maximum span NaN is negative or NaN");
+}
+
+#[test]
+fn invalid_sliders_are_shown_as_errors() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f64>::new(200.0, 300.0, 1.0..=1000.0, config)
+            .options(|slider| slider.separation_distance(-1.0));
+        let output = harness.frame(Vec::new());
+
+        // the values are left alone
+        assert_eq!(harness.values(), (200.0, 300.0), "{config:?}");
+
+        // an outline in the error color is drawn instead of the slider
+        let error_color = Visuals::dark().error_fg_color;
+        let outlined = output.shapes.iter().any(|ClippedShape { shape, .. }| {
+            matches!(shape, Shape::Rect(rect) if rect.stroke.color == error_color)
+        });
+        assert!(outlined, "{:#?} ({config:?})", output.shapes);
+    }
+}
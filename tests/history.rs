@@ -0,0 +1,132 @@
+//! Tests of the history of selections, see [`SliderHistory`].
+
+mod common;
+
+use common::{Harness, CONFIGS};
+use egui::{Event, Key, Modifiers, Vec2};
+use egui_double_slider::SliderHistory;
+
+fn history(harness: &Harness<i32>) -> SliderHistory {
+    SliderHistory::load(harness.ctx(), harness.id()).expect("the slider has a history")
+}
+
+fn selections(harness: &Harness<i32>) -> Vec<(i32, i32)> {
+    let history = history(harness);
+    (0..history.len())
+        .filter_map(|index| history.get(index))
+        .collect()
+}
+
+#[test]
+fn settled_selections_are_committed() {
+    for config in CONFIGS {
+        let mut harness = Harness::<i32>::window(config).options(|slider| slider.history(true));
+        assert_eq!(selections(&harness), [(20, 80)], "{config:?}");
+
+        // a drag is committed once when it is released
+        let from = harness.point(harness.low);
+        harness.drag(from, harness.along(from, 10.0));
+        let dragged = (harness.low, harness.high);
+        assert_ne!(dragged, (20, 80), "{config:?}");
+        assert_eq!(selections(&harness), [(20, 80), dragged], "{config:?}");
+
+        // scrolling is committed once when it stops
+        harness.scroll(Vec2::new(500.0, 0.0));
+        let scrolled = (harness.low, harness.high);
+        assert_ne!(scrolled, dragged, "{config:?}");
+        assert_eq!(
+            selections(&harness),
+            [(20, 80), dragged, scrolled],
+            "{config:?}"
+        );
+        assert_eq!(history(&harness).index(), 2, "{config:?}");
+    }
+}
+
+#[test]
+fn values_set_by_the_application_are_not_committed() {
+    for config in CONFIGS {
+        let mut harness = Harness::<i32>::window(config).options(|slider| slider.history(true));
+        (harness.low, harness.high) = (30, 60);
+        harness.frame(Vec::new());
+        harness.frame(Vec::new());
+        assert_eq!(selections(&harness), [(20, 80)], "{config:?}");
+
+        // a paste is committed right away
+        harness.click(harness.point(45));
+        harness.frame(vec![Event::Paste("40..=50".to_owned())]);
+        harness.frame(Vec::new());
+        assert_eq!(selections(&harness), [(20, 80), (40, 50)], "{config:?}");
+    }
+}
+
+#[test]
+fn shortcuts_undo_and_redo() {
+    for config in CONFIGS {
+        let mut harness = Harness::<i32>::window(config).options(|slider| slider.history(true));
+        // dragging focuses the slider
+        let from = harness.point(harness.high);
+        harness.drag(from, harness.along(from, -20.0));
+        let dragged = (harness.low, harness.high);
+
+        harness.key(Key::Z, Modifiers::COMMAND);
+        assert_eq!((harness.low, harness.high), (20, 80), "{config:?}");
+        assert!(!history(&harness).can_undo(), "{config:?}");
+
+        harness.key(Key::Z, Modifiers::COMMAND | Modifiers::SHIFT);
+        assert_eq!((harness.low, harness.high), dragged, "{config:?}");
+        assert!(!history(&harness).can_redo(), "{config:?}");
+    }
+}
+
+#[test]
+fn new_selections_replace_undone_ones() {
+    let mut harness = Harness::<i32>::window(CONFIGS[0]).options(|slider| slider.history(true));
+    let from = harness.point(harness.low);
+    harness.drag(from, harness.along(from, 20.0));
+    harness.key(Key::Z, Modifiers::COMMAND);
+
+    let from = harness.point(harness.high);
+    harness.drag(from, harness.along(from, 10.0));
+    assert_eq!(
+        selections(&harness),
+        [(20, 80), (harness.low, harness.high)]
+    );
+}
+
+#[test]
+fn application_navigates_the_history() {
+    let mut harness = Harness::<i32>::window(CONFIGS[0]).options(|slider| slider.history(true));
+    let from = harness.point(harness.low);
+    harness.drag(from, harness.along(from, 20.0));
+    let dragged = (harness.low, harness.high);
+
+    let mut history = history(&harness);
+    assert!(history.undo());
+    assert!(!history.undo());
+    history.store(harness.ctx(), harness.id());
+    harness.frame(Vec::new());
+    assert_eq!((harness.low, harness.high), (20, 80));
+
+    let mut history = self::history(&harness);
+    assert!(history.go_to(1));
+    assert!(!history.go_to(2));
+    history.store(harness.ctx(), harness.id());
+    harness.frame(Vec::new());
+    assert_eq!((harness.low, harness.high), dragged);
+
+    // clearing starts again with the current selection
+    SliderHistory::clear(harness.ctx(), harness.id());
+    harness.frame(Vec::new());
+    assert_eq!(selections(&harness), [dragged]);
+}
+
+#[test]
+fn large_integers_are_kept_exactly() {
+    let (low, high) = (u64::MAX - 1000, u64::MAX - 1);
+    let mut harness = Harness::with_values(low, high, 0..=u64::MAX, CONFIGS[0])
+        .options(|slider| slider.history(true));
+    harness.frame(Vec::new());
+    let history = SliderHistory::load(harness.ctx(), harness.id()).expect("history");
+    assert_eq!(history.current::<u64>(), (low, high));
+}
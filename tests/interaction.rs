@@ -0,0 +1,368 @@
+//! Headless tests driving [`DoubleSlider`] with simulated input events.
+//!
+//! Every interaction is checked for all combinations of orientation, linear/logarithmic scale
+//! and integer/float values.
+
+mod common;
+
+use common::{assert_near, for_all_configs, Config, Harness};
+use egui::emath::Numeric;
+use egui::epaint::{ClippedShape, Color32, Shape, Stroke};
+use egui::{FullOutput, Modifiers, Pos2, SliderOrientation, Vec2};
+use egui_double_slider::{DoubleSliderStyle, PartStyle, PartVisuals};
+use std::fmt::Debug;
+
+fn drag_lower_point<T: Numeric + Debug>(config: Config) {
+    let mut harness = Harness::<T>::new(100.0, 500.0, 1.0..=1000.0, config);
+    let from = harness.point(harness.low);
+    let to = harness.along(from, -50.0);
+    let expected = harness.mapping().value_from_screen(to);
+    harness.drag(from, to);
+
+    let (low, high) = harness.values();
+    assert_near(
+        low,
+        expected,
+        harness.resolution(expected),
+        "lower value",
+        config,
+    );
+    assert_eq!(high, 500.0, "upper value ({config:?})");
+}
+
+fn drag_upper_point<T: Numeric + Debug>(config: Config) {
+    let mut harness = Harness::<T>::new(100.0, 500.0, 1.0..=1000.0, config);
+    let from = harness.point(harness.high);
+    let to = harness.along(from, 40.0);
+    let expected = harness.mapping().value_from_screen(to);
+    harness.drag(from, to);
+
+    let (low, high) = harness.values();
+    assert_eq!(low, 100.0, "lower value ({config:?})");
+    assert_near(
+        high,
+        expected,
+        harness.resolution(expected),
+        "upper value",
+        config,
+    );
+}
+
+fn drag_point_precisely<T: Numeric + Debug>(config: Config) {
+    let mut harness = Harness::<T>::new(100.0, 500.0, 1.0..=1000.0, config);
+    harness.modifiers = Modifiers::SHIFT;
+    let from = harness.point(harness.low);
+    // the default precision factor scales the pointer movement down to a tenth
+    let expected = harness
+        .mapping()
+        .value_from_screen(harness.along(from, 5.0));
+    harness.drag(from, harness.along(from, 50.0));
+
+    let (low, high) = harness.values();
+    assert_near(
+        low,
+        expected,
+        harness.resolution(expected),
+        "lower value",
+        config,
+    );
+    assert_eq!(high, 500.0, "upper value ({config:?})");
+}
+
+fn drag_point_beyond_the_end<T: Numeric + Debug>(config: Config) {
+    let mut harness = Harness::<T>::new(100.0, 500.0, 1.0..=1000.0, config);
+    let from = harness.point(harness.high);
+    let to = harness.along(from, 1000.0);
+    harness.drag(from, to);
+
+    assert_eq!(harness.values(), (100.0, 1000.0), "{config:?}");
+}
+
+fn drag_point_past_the_other<T: Numeric + Debug>(config: Config) {
+    let mut harness = Harness::<T>::new(100.0, 500.0, 1.0..=1000.0, config);
+    let from = harness.point(harness.low);
+    let to = harness.along(harness.point(harness.high), 30.0);
+    harness.drag(from, to);
+
+    // the upper value is pushed ahead
+    let (low, high) = harness.values();
+    assert!(low > 500.0, "lower value {low} ({config:?})");
+    assert!(high >= low + 1.0, "upper value {high} ({config:?})");
+}
+
+fn drag_in_between<T: Numeric + Debug>(config: Config) {
+    let mut harness = Harness::<T>::new(100.0, 500.0, 1.0..=1000.0, config);
+    let mapping = harness.mapping();
+    let (low_pos, high_pos) = (
+        mapping.position_from_value(100.0),
+        mapping.position_from_value(500.0),
+    );
+    let from = mapping.screen_from_value(mapping.value_from_position((low_pos + high_pos) / 2.0));
+    harness.drag(from, harness.along(from, 20.0));
+
+    // both control points are moved by the same distance
+    let (low, high) = harness.values();
+    let moved = |value: f64| {
+        mapping.value_from_screen(harness.along(mapping.screen_from_value(value), 20.0))
+    };
+    let (expected_low, expected_high) = (moved(100.0), moved(500.0));
+    assert_near(
+        low,
+        expected_low,
+        harness.resolution(expected_low),
+        "lower value",
+        config,
+    );
+    assert_near(
+        high,
+        expected_high,
+        harness.resolution(expected_high),
+        "upper value",
+        config,
+    );
+}
+
+fn double_click_reset<T: Numeric + Debug>(config: Config) {
+    let mut harness = Harness::<T>::new(100.0, 500.0, 1.0..=1000.0, config)
+        .options(|slider| slider.reset_on_double_click(T::from_f64(200.0), T::from_f64(800.0)));
+    let point = harness.point(harness.low);
+    harness.click(point);
+    harness.click(point);
+    assert_eq!(harness.values(), (200.0, 500.0), "{config:?}");
+
+    // double clicking the in-between part resets both values, after the double click delay
+    for _ in 0..30 {
+        harness.frame(Vec::new());
+    }
+    let mapping = harness.mapping();
+    let between = (mapping.position_from_value(200.0) + mapping.position_from_value(500.0)) / 2.0;
+    let point = mapping.screen_from_value(mapping.value_from_position(between));
+    harness.click(point);
+    harness.click(point);
+    assert_eq!(harness.values(), (200.0, 800.0), "{config:?}");
+}
+
+fn scroll<T: Numeric + Debug>(config: Config) {
+    let mut harness = Harness::<T>::new(100.0, 500.0, 1.0..=1000.0, config);
+    harness.scroll(Vec2::new(500.0, 0.0));
+    let (low, high) = harness.values();
+    assert!(low > 100.0, "lower value {low} ({config:?})");
+    assert!(high > 500.0, "upper value {high} ({config:?})");
+
+    // scrolling stops at the ends of the range
+    harness.scroll(Vec2::new(1e5, 0.0));
+    let (low, high) = harness.values();
+    assert!(low < high, "{low} < {high} ({config:?})");
+    assert_eq!(high, 1000.0, "upper value ({config:?})");
+    harness.scroll(Vec2::new(-1e5, 0.0));
+    let (low, high) = harness.values();
+    assert!(low < high, "{low} < {high} ({config:?})");
+    assert_eq!(low, 1.0, "lower value ({config:?})");
+}
+
+fn zoom<T: Numeric + Debug>(config: Config) {
+    let mut harness = Harness::<T>::new(100.0, 500.0, 1.0..=1000.0, config);
+    harness.zoom(1.5);
+    let (low, high) = harness.values();
+    assert!(low < 100.0, "lower value {low} ({config:?})");
+    assert!(high > 500.0, "upper value {high} ({config:?})");
+
+    harness.zoom(0.8);
+    let (zoomed_low, zoomed_high) = harness.values();
+    assert!(zoomed_low > low, "lower value {zoomed_low} ({config:?})");
+    assert!(zoomed_high < high, "upper value {zoomed_high} ({config:?})");
+
+    // zooming out stops at the ends of the range
+    for _ in 0..10 {
+        harness.zoom(100.0);
+    }
+    assert_eq!(harness.values(), (1.0, 1000.0), "{config:?}");
+}
+
+fn single_valued_range<T: Numeric + Debug>(config: Config) {
+    let mut harness = Harness::<T>::new(5.0, 5.0, 5.0..=5.0, config);
+    let point = harness.point(harness.low);
+    harness.drag(point, harness.along(point, 30.0));
+    harness.scroll(Vec2::new(20.0, 0.0));
+    harness.zoom(1.5);
+
+    assert_eq!(harness.values(), (5.0, 5.0), "{config:?}");
+}
+
+#[test]
+fn dragging_the_lower_point_sets_the_lower_value() {
+    for_all_configs(drag_lower_point::<i32>);
+    for_all_configs(drag_lower_point::<f64>);
+}
+
+#[test]
+fn dragging_the_upper_point_sets_the_upper_value() {
+    for_all_configs(drag_upper_point::<i32>);
+    for_all_configs(drag_upper_point::<f64>);
+}
+
+#[test]
+fn dragging_with_the_precision_modifier_scales_the_movement() {
+    for_all_configs(drag_point_precisely::<i32>);
+    for_all_configs(drag_point_precisely::<f64>);
+}
+
+#[test]
+fn dragging_beyond_the_end_stops_at_the_end() {
+    for_all_configs(drag_point_beyond_the_end::<i32>);
+    for_all_configs(drag_point_beyond_the_end::<f64>);
+}
+
+#[test]
+fn dragging_past_the_other_point_pushes_it() {
+    for_all_configs(drag_point_past_the_other::<i32>);
+    for_all_configs(drag_point_past_the_other::<f64>);
+}
+
+#[test]
+fn dragging_the_in_between_part_moves_both_values() {
+    for_all_configs(drag_in_between::<i32>);
+    for_all_configs(drag_in_between::<f64>);
+}
+
+#[test]
+fn double_clicking_resets_the_values() {
+    for_all_configs(double_click_reset::<i32>);
+    for_all_configs(double_click_reset::<f64>);
+}
+
+#[test]
+fn scrolling_moves_both_values_within_the_range() {
+    for_all_configs(scroll::<i32>);
+    for_all_configs(scroll::<f64>);
+}
+
+#[test]
+fn zooming_changes_the_span_within_the_range() {
+    for_all_configs(zoom::<i32>);
+    for_all_configs(zoom::<f64>);
+}
+
+#[test]
+fn single_valued_range_keeps_its_value() {
+    for_all_configs(single_valued_range::<i32>);
+    for_all_configs(single_valued_range::<f64>);
+}
+
+// distinct colors for every part of the slider
+fn test_style() -> DoubleSliderStyle {
+    let part = |color| PartStyle::uniform(PartVisuals::new(color, Stroke::NONE));
+    DoubleSliderStyle {
+        track: part(Color32::GRAY),
+        band: part(Color32::RED),
+        lower_handle: part(Color32::GREEN),
+        upper_handle: part(Color32::BLUE),
+    }
+}
+
+fn shapes(output: FullOutput) -> Vec<Shape> {
+    output
+        .shapes
+        .into_iter()
+        .map(|ClippedShape { shape, .. }| shape)
+        .collect()
+}
+
+#[test]
+fn shapes_of_the_slider() {
+    for_all_configs(|config| {
+        let mut harness = Harness::<f64>::new(100.0, 500.0, 1.0..=1000.0, config)
+            .options(|slider| slider.style(test_style()));
+        let shapes = shapes(harness.frame(Vec::new()));
+        let (low, high) = (harness.point(100.0), harness.point(500.0));
+
+        // the line, the highlighted part and the control points, in this order
+        let [Shape::Rect(track), Shape::Rect(band), Shape::Circle(lower), Shape::Circle(upper)] =
+            shapes.as_slice()
+        else {
+            panic!("unexpected shapes {shapes:#?} ({config:?})");
+        };
+        assert_eq!(track.fill, Color32::GRAY, "{config:?}");
+        assert_eq!(band.fill, Color32::RED, "{config:?}");
+        assert_eq!(
+            (lower.center, lower.fill),
+            (low, Color32::GREEN),
+            "{config:?}"
+        );
+        assert_eq!(
+            (upper.center, upper.fill),
+            (high, Color32::BLUE),
+            "{config:?}"
+        );
+
+        // the highlighted part lies on the line, between the centers of the control points
+        assert!(track.rect.contains_rect(band.rect), "{config:?}");
+        let (band_start, band_end, low, high) = match config.orientation {
+            SliderOrientation::Horizontal => (band.rect.left(), band.rect.right(), low.x, high.x),
+            SliderOrientation::Vertical => (-band.rect.bottom(), -band.rect.top(), -low.y, -high.y),
+        };
+        // the ends are extended below the control points by the corner radius
+        let extension = f32::from(band.corner_radius.nw);
+        assert!((low - band_start - extension).abs() <= 0.5, "{config:?}");
+        assert!((band_end - high - extension).abs() <= 0.5, "{config:?}");
+    });
+}
+
+#[test]
+fn shapes_follow_the_values() {
+    for_all_configs(|config| {
+        let mut harness = Harness::<i32>::new(100.0, 500.0, 1.0..=1000.0, config)
+            .options(|slider| slider.style(test_style()));
+        let from = harness.point(harness.low);
+        harness.drag(from, harness.along(from, 60.0));
+        let shapes = shapes(harness.frame(Vec::new()));
+        let centers: Vec<Pos2> = shapes
+            .iter()
+            .filter_map(|shape| match shape {
+                Shape::Circle(circle) => Some(circle.center),
+                _ => None,
+            })
+            .collect();
+
+        let expected = [harness.point(harness.low), harness.point(harness.high)];
+        assert_eq!(centers.len(), 2, "{config:?}");
+        for (center, expected) in centers.into_iter().zip(expected) {
+            assert!(
+                center.distance(expected) < 1e-3,
+                "{center:?} is not at {expected:?} ({config:?})"
+            );
+        }
+    });
+}
+
+#[test]
+fn focused_sliders_show_every_part_as_focused() {
+    for_all_configs(|config| {
+        let focused = |style: PartStyle| PartStyle {
+            focused: PartVisuals::new(Color32::YELLOW, Stroke::NONE),
+            ..style
+        };
+        let style = test_style();
+        let style = DoubleSliderStyle {
+            track: focused(style.track),
+            band: focused(style.band),
+            lower_handle: focused(style.lower_handle),
+            upper_handle: focused(style.upper_handle),
+        };
+        let mut harness = Harness::<f64>::new(100.0, 500.0, 1.0..=1000.0, config)
+            .options(move |slider| slider.style(style));
+        let id = harness.id();
+        harness.ctx().memory_mut(|memory| memory.request_focus(id));
+        let shapes = shapes(harness.frame(Vec::new()));
+
+        let fills: Vec<Color32> = shapes
+            .iter()
+            .filter_map(|shape| match shape {
+                Shape::Rect(rect) => Some(rect.fill),
+                Shape::Circle(circle) => Some(circle.fill),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fills, [Color32::YELLOW; 4], "{config:?}");
+    });
+}
@@ -0,0 +1,265 @@
+//! Property tests of the invariants of [`DoubleSlider`] values after random sequences of input events,
+//! with random ranges, separation distances, maximum spans and collision policies.
+
+mod common;
+
+use common::{Config, Harness, CONFIGS};
+use egui::emath::Numeric;
+use egui::{Pos2, Vec2};
+use egui_double_slider::CollisionPolicy;
+use std::fmt::Debug;
+
+// number of random sliders per value type
+const CASES: u64 = 48;
+
+// number of random interactions with each slider
+const ACTIONS: usize = 12;
+
+const POLICIES: [CollisionPolicy; 3] = [
+    CollisionPolicy::Push,
+    CollisionPolicy::Block,
+    CollisionPolicy::Swap,
+];
+
+/// A random slider and what was chosen for it, to reproduce failures.
+#[derive(Clone, Copy, Debug)]
+struct Case {
+    seed: u64,
+    config: Config,
+    range: (f64, f64),
+    separation: f64,
+    max_span: Option<f64>,
+    policy: CollisionPolicy,
+}
+
+impl Case {
+    fn random<T: Numeric>(seed: u64) -> Self {
+        let mut rng = fastrand::Rng::with_seed(seed);
+        let config = CONFIGS[rng.usize(..CONFIGS.len())];
+        let value = |rng: &mut fastrand::Rng| {
+            let value = if config.logarithmic {
+                // strictly positive values over several orders of magnitude
+                10f64.powf(rng.f64() * 4.0 - 1.0)
+            } else {
+                rng.f64() * 2000.0 - 1000.0
+            };
+            match (T::INTEGRAL, config.logarithmic) {
+                (true, true) => value.round().max(1.0),
+                (true, false) => value.round(),
+                (false, _) => value,
+            }
+        };
+        let range = if rng.u8(..10) == 0 {
+            // single valued range
+            let single = value(&mut rng);
+            (single, single)
+        } else {
+            (value(&mut rng), value(&mut rng))
+        };
+        let span = (range.1 - range.0).abs();
+        // the separation is larger than the range in about a third of the cases
+        let separation = rng.f64() * 1.5 * span;
+        let separation = if T::INTEGRAL {
+            separation.round()
+        } else {
+            separation
+        };
+        // half of the sliders have a maximum span, which is at least the separation distance
+        let max_span = rng.bool().then(|| {
+            let max_span = separation + rng.f64() * span;
+            if T::INTEGRAL {
+                max_span.round()
+            } else {
+                max_span
+            }
+        });
+        Self {
+            seed,
+            config,
+            range,
+            separation,
+            max_span,
+            policy: POLICIES[rng.usize(..POLICIES.len())],
+        }
+    }
+
+    fn harness<T: Numeric + Debug>(&self) -> Harness<T> {
+        let (start, end) = self.range;
+        let (separation, max_span, policy) = (self.separation, self.max_span, self.policy);
+        Harness::new(start, end, start..=end, self.config).options(move |slider| {
+            let slider = slider
+                .separation_distance(T::from_f64(separation))
+                .collision_policy(policy);
+            match max_span {
+                Some(max_span) => slider.max_span(T::from_f64(max_span)),
+                None => slider,
+            }
+        })
+    }
+
+    fn check<T: Numeric + Debug>(&self, harness: &Harness<T>, after: &str) {
+        let (low, high) = harness.values();
+        let (start, end) = self.range;
+        let (min, max) = (start.min(end), start.max(end));
+        let context = format!(
+            "after {after}: {low}, {high} (seed {}, {self:?})",
+            self.seed
+        );
+
+        assert!(!low.is_nan() && !high.is_nan(), "NaN {context}");
+        assert!((min..=max).contains(&low), "lower value outside {context}");
+        assert!((min..=max).contains(&high), "upper value outside {context}");
+        if T::INTEGRAL {
+            assert!(low == low.round() && high == high.round(), "{context}");
+        }
+        // the separation is kept as far as the range allows
+        let separation = self.separation.min(max - min);
+        let tolerance = 1e-9 * max.abs().max(min.abs());
+        assert!(
+            high - low >= separation - tolerance,
+            "separation {separation} {context}"
+        );
+        if let Some(max_span) = self.max_span {
+            assert!(
+                high - low <= max_span + tolerance,
+                "maximum span {max_span} {context}"
+            );
+        }
+    }
+}
+
+// A random point near the slider, often on one of the control points to grab it
+fn random_point<T: Numeric + Debug>(rng: &mut fastrand::Rng, harness: &Harness<T>) -> Pos2 {
+    match rng.u8(..4) {
+        0 => harness.point(harness.low),
+        1 => harness.point(harness.high),
+        _ => {
+            let rect = harness.mapping().rect().expand(30.0);
+            Pos2::new(
+                rect.left() + rng.f32() * rect.width(),
+                rect.top() + rng.f32() * rect.height(),
+            )
+        }
+    }
+}
+
+fn random_interactions<T: Numeric + Debug>(seed: u64) {
+    let case = Case::random::<T>(seed);
+    let mut harness = case.harness::<T>();
+    case.check(&harness, "showing the slider");
+
+    let mut rng = fastrand::Rng::with_seed(seed);
+    for _ in 0..ACTIONS {
+        let action = match rng.u8(..5) {
+            0 | 1 => {
+                let from = random_point(&mut rng, &harness);
+                let to = random_point(&mut rng, &harness) + Vec2::splat(rng.f32() * 100.0 - 50.0);
+                harness.drag(from, to);
+                format!("dragging from {from:?} to {to:?}")
+            }
+            2 => {
+                let delta = Vec2::new(rng.f32() * 400.0 - 200.0, rng.f32() * 400.0 - 200.0);
+                harness.scroll(delta);
+                format!("scrolling by {delta:?}")
+            }
+            3 => {
+                let factor = 0.25 + rng.f32() * 3.0;
+                harness.zoom(factor);
+                format!("zooming by {factor}")
+            }
+            _ => {
+                // the application sets arbitrary values, also outside of the range
+                let (start, end) = case.range;
+                let span = (end - start).abs().max(1.0);
+                let mut value = || {
+                    if !T::INTEGRAL && rng.u8(..8) == 0 {
+                        f64::NAN
+                    } else {
+                        start.min(end) - span + rng.f64() * 3.0 * span
+                    }
+                };
+                let (low, high) = (value(), value());
+                (harness.low, harness.high) = (T::from_f64(low), T::from_f64(high));
+                harness.frame(Vec::new());
+                format!("setting {low}, {high}")
+            }
+        };
+        case.check(&harness, &action);
+    }
+}
+
+#[test]
+fn invariants_hold_after_random_interactions() {
+    for seed in 0..CASES {
+        random_interactions::<f64>(seed);
+        random_interactions::<i32>(seed);
+    }
+}
+
+#[test]
+fn separation_larger_than_the_range_keeps_the_ends() {
+    for config in CONFIGS {
+        let mut harness = Harness::<i32>::new(3.0, 5.0, 1.0..=10.0, config)
+            .options(|slider| slider.separation_distance(20));
+        assert_eq!(harness.values(), (1.0, 10.0), "{config:?}");
+
+        // dragging the lower value does not push the upper one out of the range
+        let from = harness.point(harness.low);
+        harness.drag(from, harness.along(from, 100.0));
+        assert_eq!(harness.values(), (1.0, 10.0), "{config:?}");
+    }
+}
+
+#[test]
+fn invalid_values_are_corrected() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f64>::new(f64::NAN, 2000.0, 1.0..=1000.0, config);
+        assert_eq!(harness.values(), (1.0, 1000.0), "{config:?}");
+
+        // values in the wrong order are swapped
+        (harness.low, harness.high) = (600.0, 200.0);
+        harness.frame(Vec::new());
+        assert_eq!(harness.values(), (200.0, 600.0), "{config:?}");
+
+        // values too close to each other are moved apart around their center
+        (harness.low, harness.high) = (400.0, 400.0);
+        harness.frame(Vec::new());
+        assert_eq!(harness.values(), (399.5, 400.5), "{config:?}");
+    }
+}
+
+#[test]
+fn values_farther_apart_than_the_maximum_span_are_moved_together() {
+    for config in CONFIGS {
+        let harness = Harness::<f64>::new(1.0, 51.0, 1.0..=100.0, config)
+            .options(|slider| slider.max_span(30.0));
+        assert_eq!(harness.values(), (11.0, 41.0), "{config:?}");
+        assert!(harness.changed, "{config:?}");
+    }
+}
+
+#[test]
+fn read_only_and_disabled_sliders_leave_the_values_alone() {
+    for config in CONFIGS {
+        let mut read_only = Harness::<f64>::new(5.0, 5.0, 1.0..=10.0, config)
+            .options(|slider| slider.read_only(true));
+        let mut disabled = Harness::<f64>::new(5.0, 5.0, 1.0..=10.0, config);
+        disabled.enabled = false;
+        for harness in [&mut read_only, &mut disabled] {
+            // the first frame of the harness corrected the values
+            (harness.low, harness.high) = (5.0, 5.0);
+            harness.frame(Vec::new());
+            assert_eq!(harness.values(), (5.0, 5.0), "{config:?}");
+
+            // the corrected values are drawn, but cannot be dragged
+            let from = harness.mapping().screen_from_value(4.5);
+            harness.drag(from, harness.along(from, -30.0));
+            assert_eq!(harness.values(), (5.0, 5.0), "{config:?}");
+        }
+
+        // the values are corrected as soon as the slider is enabled
+        disabled.enabled = true;
+        disabled.frame(Vec::new());
+        assert_eq!(disabled.values(), (4.5, 5.5), "{config:?}");
+    }
+}
@@ -0,0 +1,107 @@
+//! Tests of 64-bit integer values beyond the precision of `f64` (2^53), e.g. nanosecond timestamps.
+
+mod common;
+
+use common::{Harness, CONFIGS};
+use egui::{Event, OutputCommand};
+use std::ops::RangeInclusive;
+
+// 2023-11-14 in nanoseconds since the Unix epoch, not representable as `f64`
+const START: u64 = 1_700_000_000_000_000_123;
+
+// one second
+const SPAN: u64 = 1_000_000_000;
+
+const RANGE: RangeInclusive<u64> = START..=START + SPAN;
+
+#[test]
+fn values_are_kept_exactly() {
+    for config in CONFIGS.into_iter().filter(|config| !config.logarithmic) {
+        let (low, high) = (START + 100_000_001, START + 900_000_007);
+        let mut harness = Harness::with_values(low, high, RANGE, config);
+        for _ in 0..10 {
+            harness.frame(Vec::new());
+        }
+        assert_eq!((harness.low, harness.high), (low, high), "{config:?}");
+    }
+}
+
+#[test]
+fn dragging_to_the_ends_hits_them_exactly() {
+    for config in CONFIGS {
+        let mut harness =
+            Harness::with_values(START + 100_000_001, START + 900_000_007, RANGE, config);
+        let from = harness.point(harness.low);
+        harness.drag(from, harness.along(from, -1000.0));
+        let from = harness.point(harness.high);
+        harness.drag(from, harness.along(from, 1000.0));
+
+        assert_eq!(
+            (harness.low, harness.high),
+            (START, START + SPAN),
+            "{config:?}"
+        );
+    }
+}
+
+#[test]
+fn dragging_lands_on_values_between_those_of_f64() {
+    for config in CONFIGS.into_iter().filter(|config| !config.logarithmic) {
+        let mut harness =
+            Harness::with_values(START + 100_000_001, START + 900_000_007, RANGE, config);
+        let from = harness.point(harness.low);
+        let to = harness.along(from, 50.0);
+        let expected =
+            START as f64 + harness.mapping().normalized_from_position(to.x.max(to.y)) * SPAN as f64;
+        harness.drag(from, to);
+
+        // the value is within a pixel of the expected one, and is not rounded to a multiple of 256 like an `f64`
+        let resolution = SPAN as f64 / harness.mapping().rect().size().max_elem() as f64;
+        assert!(
+            (harness.low as f64 - expected).abs() <= resolution,
+            "{config:?}"
+        );
+        assert_ne!(harness.low % 256, START % 256, "{config:?}");
+        assert_eq!(harness.high, START + 900_000_007, "{config:?}");
+    }
+}
+
+#[test]
+fn scrolling_keeps_the_span_exactly() {
+    for config in CONFIGS.into_iter().filter(|config| !config.logarithmic) {
+        let mut harness =
+            Harness::with_values(START + 100_000_001, START + 200_000_004, RANGE, config);
+        harness.scroll(egui::Vec2::new(300.0, 0.0));
+
+        assert_ne!(harness.low, START + 100_000_001, "{config:?}");
+        assert_eq!(harness.high - harness.low, 100_000_003, "{config:?}");
+    }
+}
+
+#[test]
+fn copy_and_paste_are_exact() {
+    let config = CONFIGS[0];
+    let mut harness = Harness::with_values(START + 100_000_001, START + 900_000_007, RANGE, config);
+    // focus the slider by clicking between the control points
+    let center = harness.point(START + 500_000_000);
+    harness.click(center);
+
+    let output = harness.frame(vec![Event::Copy]);
+    let copied = output
+        .platform_output
+        .commands
+        .iter()
+        .find_map(|command| match command {
+            OutputCommand::CopyText(text) => Some(text.clone()),
+            _ => None,
+        });
+    assert_eq!(
+        copied.as_deref(),
+        Some("1700000000100000124..=1700000000900000130")
+    );
+
+    harness.frame(vec![Event::Paste(
+        "[1700000000000000125, 1700000000999999999]".to_owned(),
+    )]);
+    assert_eq!((harness.low, harness.high), (START + 2, START + SPAN - 124));
+}
@@ -0,0 +1,90 @@
+//! Tests of the space that [`DoubleSlider`] takes in different layouts.
+
+use egui::{Align, Context, FontDefinitions, Layout, Pos2, RawInput, Rect, SliderOrientation, Ui};
+use egui_double_slider::DoubleSlider;
+
+const SCREEN: Rect = Rect::from_min_max(Pos2::ZERO, Pos2::new(400.0, 300.0));
+
+// Shows `add_contents` in one frame on a headless context
+fn frame(add_contents: impl FnOnce(&mut Ui)) {
+    let ctx = Context::default();
+    ctx.set_fonts(FontDefinitions::empty());
+    let input = RawInput {
+        screen_rect: Some(SCREEN),
+        ..Default::default()
+    };
+    let mut add_contents = Some(add_contents);
+    let _ = ctx.run_ui(input, |ui| {
+        if let Some(add_contents) = add_contents.take() {
+            add_contents(ui);
+        }
+    });
+}
+
+// Adds a slider and a button after it, returning the rect of the slider, the button and the available space before
+fn slider_and_button(ui: &mut Ui, orientation: SliderOrientation) -> (Rect, Rect, Rect) {
+    let available = ui.available_rect_before_wrap();
+    let (mut low, mut high) = (20.0, 80.0);
+    let slider =
+        ui.add(DoubleSlider::new(&mut low, &mut high, 0.0..=100.0).orientation(orientation));
+    let button = ui.button("after");
+    (slider.rect, button.rect, available)
+}
+
+#[test]
+fn sliders_fill_the_space_across_the_layout() {
+    let mut rects = None;
+    frame(|ui| rects = Some(slider_and_button(ui, SliderOrientation::Horizontal)));
+    let (slider, _, available) = rects.unwrap();
+    assert_eq!(slider.width(), available.width());
+
+    let mut rects = None;
+    frame(|ui| {
+        ui.horizontal(|ui| {
+            ui.set_min_height(200.0);
+            rects = Some(slider_and_button(ui, SliderOrientation::Vertical));
+        });
+    });
+    let (slider, _, available) = rects.unwrap();
+    assert_eq!(slider.height(), available.height());
+}
+
+#[test]
+fn sliders_along_the_layout_leave_space_for_later_widgets() {
+    for (orientation, layout) in [
+        (
+            SliderOrientation::Horizontal,
+            Layout::left_to_right(Align::Center),
+        ),
+        (SliderOrientation::Vertical, Layout::top_down(Align::Min)),
+    ] {
+        let mut rects = None;
+        let mut slider_width = 0.0;
+        frame(|ui| {
+            slider_width = ui.spacing().slider_width;
+            ui.with_layout(layout, |ui| {
+                rects = Some(slider_and_button(ui, orientation))
+            });
+        });
+        let (slider, button, _) = rects.unwrap();
+        let along = match orientation {
+            SliderOrientation::Horizontal => slider.width(),
+            SliderOrientation::Vertical => slider.height(),
+        };
+        assert_eq!(along, slider_width, "{orientation:?}");
+        assert!(SCREEN.contains_rect(button), "{orientation:?}");
+    }
+}
+
+#[test]
+fn sliders_fill_justified_layouts() {
+    let mut rects = None;
+    frame(|ui| {
+        let layout = Layout::left_to_right(Align::Center).with_main_justify(true);
+        ui.with_layout(layout, |ui| {
+            rects = Some(slider_and_button(ui, SliderOrientation::Horizontal));
+        });
+    });
+    let (slider, _, available) = rects.unwrap();
+    assert_eq!(slider.width(), available.width());
+}
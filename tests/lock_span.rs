@@ -0,0 +1,92 @@
+//! Tests of a locked span, which moves a window of fixed span over the range.
+
+mod common;
+
+use common::{text_rect, Harness, CONFIGS};
+use egui::{Event, Vec2};
+use egui_double_slider::DoubleSlider;
+
+fn locked(slider: DoubleSlider<'_, f64>) -> DoubleSlider<'_, f64> {
+    slider
+        .lock_span(true)
+        .reset_on_double_click(10.0, 30.0)
+        .context_menu(true)
+}
+
+fn span(harness: &Harness<f64>) -> f64 {
+    harness.high - harness.low
+}
+
+#[test]
+fn dragging_and_scrolling_move_the_window() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(locked);
+        let from = harness.point(50.0);
+        harness.drag(from, harness.along(from, 20.0));
+        assert!(harness.low > 20.0, "{} {config:?}", harness.low);
+        assert!((span(&harness) - 60.0).abs() < 1e-9, "{config:?}");
+
+        harness.scroll(Vec2::new(-100.0, 0.0));
+        assert!((span(&harness) - 60.0).abs() < 1e-9, "{config:?}");
+
+        // zooming is disabled
+        let values = harness.values();
+        harness.zoom(1.5);
+        assert_eq!(harness.values(), values, "{config:?}");
+    }
+}
+
+#[test]
+fn pasting_only_accepts_the_locked_span() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(locked);
+        // focus the slider by clicking between the control points, which keeps the centered window
+        harness.click(harness.point(50.0));
+
+        harness.frame(vec![Event::Paste("10..=90".to_owned())]);
+        assert_eq!(harness.values(), (20.0, 80.0), "{config:?}");
+
+        harness.frame(vec![Event::Paste("30..=90".to_owned())]);
+        assert_eq!(harness.values(), (30.0, 90.0), "{config:?}");
+    }
+}
+
+#[test]
+fn resetting_moves_the_window_to_the_lower_default() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(locked);
+        let center = harness.point(50.0);
+        harness.click(center);
+        harness.click(center);
+        assert_eq!(harness.values(), (10.0, 70.0), "{config:?}");
+    }
+}
+
+#[test]
+fn context_menu_moves_the_window() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(locked);
+        harness.secondary_click(harness.point(50.0));
+        let output = harness.frame(Vec::new());
+        assert!(
+            text_rect(&output, "Select full range").is_none(),
+            "{config:?}"
+        );
+
+        // the upper value is entered, the lower one follows
+        let label = text_rect(&output, "Upper:").expect("the context menu is open");
+        let upper = text_rect(&output, "80").expect("the upper value is shown");
+        assert!(
+            (upper.center().y - label.center().y).abs() < 1.0,
+            "{config:?}"
+        );
+        harness.drag(upper.center(), upper.center() + Vec2::new(10.0, 0.0));
+        assert!(harness.high > 80.0, "{} {config:?}", harness.high);
+        assert_eq!(span(&harness), 60.0, "{config:?}");
+
+        let output = harness.frame(Vec::new());
+        let reset = text_rect(&output, "Reset").expect("the context menu is still open");
+        harness.click(reset.center());
+        assert_eq!(harness.values(), (10.0, 70.0), "{config:?}");
+    }
+}
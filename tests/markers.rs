@@ -0,0 +1,87 @@
+//! Tests of markers of reference values, drawn across the slider and usable as snap targets.
+
+mod common;
+
+use common::{line_at, texts, Harness, CONFIGS};
+use egui::{Event, SliderOrientation, Vec2};
+use egui_double_slider::{DoubleSlider, Marker};
+
+// frames until a tooltip is shown
+const TOOLTIP_FRAMES: usize = 60;
+
+fn markers(slider: DoubleSlider<'_, f64>) -> DoubleSlider<'_, f64> {
+    slider
+        .marker(Marker::new(42.5).label("event").tooltip("event at t=42.5"))
+        .marker(Marker::new(150.0).label("outside"))
+}
+
+#[test]
+fn markers_are_drawn_at_their_values() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(markers);
+        let output = harness.frame(Vec::new());
+        let pos = harness.mapping().position_from_value(42.5);
+        let marked = line_at(&output, pos, config.orientation);
+        assert!(marked, "{config:?}");
+
+        // markers outside of the range are not shown
+        assert_eq!(texts(&output), ["event"], "{config:?}");
+    }
+}
+
+#[test]
+fn marker_labels_get_space() {
+    for config in CONFIGS {
+        let plain = Harness::<f64>::new(20.0, 80.0, 1.0..=100.0, config).rect();
+        let labeled = Harness::window(config).options(markers).rect();
+        match config.orientation {
+            SliderOrientation::Horizontal => assert!(labeled.height() > plain.height()),
+            SliderOrientation::Vertical => assert!(labeled.width() > plain.width()),
+        }
+    }
+}
+
+#[test]
+fn hovering_a_marker_shows_its_tooltip() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(markers);
+        // on the line of the marker, just beyond the control points towards its label
+        let marker = harness.point(42.5);
+        let label_side = match config.orientation {
+            SliderOrientation::Horizontal => Vec2::new(0.0, -10.0),
+            SliderOrientation::Vertical => Vec2::new(10.0, 0.0),
+        };
+        harness.frame(vec![Event::PointerMoved(marker + label_side)]);
+        let mut output = harness.frame(Vec::new());
+        for _ in 0..TOOLTIP_FRAMES {
+            output = harness.frame(Vec::new());
+        }
+        assert!(
+            texts(&output).contains(&"event at t=42.5".to_owned()),
+            "{:?} {config:?}",
+            texts(&output)
+        );
+    }
+}
+
+#[test]
+fn dragged_control_points_snap_to_markers() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f64>::window(config).options(|slider| {
+            slider
+                .marker(Marker::new(42.5).label("event"))
+                .snap_to_markers(5.0)
+        });
+        let from = harness.point(harness.low);
+        let to = harness.along(harness.point(42.5), 3.0);
+        harness.drag(from, to);
+        assert_eq!(harness.low, 42.5, "{config:?}");
+
+        // farther away, it is not snapped
+        let from = harness.point(harness.high);
+        let to = harness.along(harness.point(42.5), 20.0);
+        harness.drag(from, to);
+        assert_ne!(harness.high, 42.5, "{config:?}");
+        assert!(harness.high > 42.5, "{config:?}");
+    }
+}
@@ -0,0 +1,125 @@
+//! Tests of presets, which set both values by clicking on them next to the slider.
+
+mod common;
+
+use common::{line_at, Harness, CONFIGS};
+use egui::epaint::{ClippedShape, Shape};
+use egui::{Pos2, SliderOrientation, Vec2};
+use egui_double_slider::{DoubleSlider, SliderHistory};
+
+// frames until the transition to a preset is done, for the default animation time of 0.2 seconds
+const ANIMATION_FRAMES: usize = 15;
+
+fn presets(slider: DoubleSlider<'_, f64>) -> DoubleSlider<'_, f64> {
+    slider
+        .preset("Calibration", 40.0..=50.0)
+        .preset("Full", 1.0..=100.0)
+}
+
+// a point on the first chip, which is right below a horizontal and right of a vertical slider
+fn first_chip(harness: &Harness<f64>) -> Pos2 {
+    let rect = harness.rect();
+    match harness.mapping().orientation() {
+        SliderOrientation::Horizontal => rect.left_bottom() + Vec2::new(4.0, 8.0),
+        SliderOrientation::Vertical => rect.right_top() + Vec2::new(8.0, 4.0),
+    }
+}
+
+#[test]
+fn clicking_a_chip_selects_the_preset() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(presets);
+        harness.click(first_chip(&harness));
+        harness.frame(Vec::new());
+        assert_eq!(harness.values(), (40.0, 50.0), "{config:?}");
+    }
+}
+
+#[test]
+fn selected_presets_are_committed_to_the_history() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(|slider| presets(slider).history(true));
+        harness.click(first_chip(&harness));
+        harness.frame(Vec::new());
+        let history = SliderHistory::load(harness.ctx(), harness.id()).expect("history");
+        assert_eq!(history.current(), (40.0, 50.0), "{config:?}");
+        assert_eq!(history.len(), 2, "{config:?}");
+    }
+}
+
+#[test]
+fn presets_are_animated() {
+    for config in CONFIGS {
+        let mut harness =
+            Harness::window(config).options(|slider| presets(slider).animate_presets(true));
+        harness.click(first_chip(&harness));
+        harness.frame(Vec::new());
+        let (low, high) = harness.values();
+        assert!(20.0 < low && low < 40.0, "{low} {config:?}");
+        assert!(50.0 < high && high < 80.0, "{high} {config:?}");
+
+        for _ in 0..ANIMATION_FRAMES {
+            harness.frame(Vec::new());
+        }
+        assert_eq!(harness.values(), (40.0, 50.0), "{config:?}");
+    }
+}
+
+#[test]
+fn markers_show_the_presets() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(presets);
+        let output = harness.frame(Vec::new());
+        let mapping = harness.mapping();
+        let marked = |value: f64| {
+            line_at(
+                &output,
+                mapping.position_from_value(value),
+                config.orientation,
+            )
+        };
+        for value in [40.0, 50.0, 1.0, 100.0] {
+            assert!(marked(value), "{value} {config:?}");
+        }
+        assert!(!marked(70.0), "{config:?}");
+
+        harness = harness.options(|slider| {
+            slider
+                .preset("Calibration", 40.0..=50.0)
+                .preset_markers(false)
+        });
+        let output = harness.frame(Vec::new());
+        let markers = output
+            .shapes
+            .iter()
+            .filter(|ClippedShape { shape, .. }| matches!(shape, Shape::LineSegment { .. }))
+            .count();
+        assert_eq!(markers, 0, "{config:?}");
+    }
+}
+
+#[test]
+fn presets_keep_a_locked_and_the_maximum_span() {
+    for config in CONFIGS {
+        // the window of 60 is moved to the start of the preset, as far as the range allows
+        let mut harness = Harness::window(config).options(|slider| presets(slider).lock_span(true));
+        harness.click(first_chip(&harness));
+        harness.frame(Vec::new());
+        assert_eq!(harness.values(), (40.0, 100.0), "{config:?}");
+
+        let mut harness = Harness::window(config)
+            .options(|slider| presets(slider).lock_span(true).animate_presets(true));
+        harness.click(first_chip(&harness));
+        for _ in 0..ANIMATION_FRAMES {
+            harness.frame(Vec::new());
+            let (low, high) = harness.values();
+            assert_eq!(high - low, 60.0, "{config:?}");
+        }
+        assert_eq!(harness.values(), (40.0, 100.0), "{config:?}");
+
+        let mut harness = Harness::window(config).options(|slider| presets(slider).max_span(5.0));
+        harness.click(first_chip(&harness));
+        harness.frame(Vec::new());
+        assert_eq!(harness.values(), (40.0, 45.0), "{config:?}");
+    }
+}
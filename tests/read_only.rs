@@ -0,0 +1,106 @@
+//! Tests of read-only and disabled sliders, which show the values without allowing to change them.
+
+mod common;
+
+use common::{texts, Harness, CONFIGS};
+use egui::epaint::{ClippedShape, Color32, Shape, Stroke};
+use egui::{Event, FullOutput, Vec2};
+use egui_double_slider::{DoubleSlider, DoubleSliderStyle, Marker, PartStyle, PartVisuals};
+
+// frames until a tooltip is shown
+const TOOLTIP_FRAMES: usize = 60;
+
+fn read_only(slider: DoubleSlider<'_, f64>) -> DoubleSlider<'_, f64> {
+    slider
+        .read_only(true)
+        .context_menu(true)
+        .reset_on_double_click(10.0, 90.0)
+        .marker(Marker::new(42.5).tooltip("event at t=42.5"))
+}
+
+// every part is drawn in gray, except when the slider is disabled
+fn style() -> DoubleSliderStyle {
+    let part = PartStyle {
+        disabled: PartVisuals::new(Color32::YELLOW, Stroke::NONE),
+        ..PartStyle::uniform(PartVisuals::new(Color32::GRAY, Stroke::NONE))
+    };
+    DoubleSliderStyle {
+        track: part,
+        band: part,
+        lower_handle: part,
+        upper_handle: part,
+    }
+}
+
+fn fills(output: FullOutput) -> Vec<Color32> {
+    output
+        .shapes
+        .into_iter()
+        .filter_map(|ClippedShape { shape, .. }| match shape {
+            Shape::Rect(rect) => Some(rect.fill),
+            Shape::Circle(circle) => Some(circle.fill),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn read_only_sliders_ignore_the_input() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(read_only);
+
+        let from = harness.point(harness.low);
+        harness.drag(from, harness.along(from, 30.0));
+        let from = harness.point(50.0);
+        harness.drag(from, harness.along(from, -30.0));
+        harness.scroll(Vec2::new(0.0, 50.0));
+        harness.zoom(2.0);
+        harness.click(harness.point(50.0));
+        harness.click(harness.point(50.0));
+        assert_eq!(harness.values(), (20.0, 80.0), "{config:?}");
+
+        // the slider cannot be focused to paste a selection and has no context menu
+        let id = harness.id();
+        harness.ctx().memory_mut(|memory| memory.request_focus(id));
+        harness.frame(vec![Event::Paste("30..=40".to_owned())]);
+        assert_eq!(harness.values(), (20.0, 80.0), "{config:?}");
+        harness.secondary_click(harness.point(50.0));
+        let output = harness.frame(Vec::new());
+        assert!(texts(&output).is_empty(), "{:?} {config:?}", texts(&output));
+    }
+}
+
+#[test]
+fn read_only_sliders_show_tooltips() {
+    for config in CONFIGS {
+        let mut harness = Harness::window(config).options(read_only);
+        harness.frame(vec![Event::PointerMoved(harness.point(42.5))]);
+        let mut output = harness.frame(Vec::new());
+        for _ in 0..TOOLTIP_FRAMES {
+            output = harness.frame(Vec::new());
+        }
+        assert!(
+            texts(&output).contains(&"event at t=42.5".to_owned()),
+            "{:?} {config:?}",
+            texts(&output)
+        );
+    }
+}
+
+#[test]
+fn only_disabled_sliders_are_drawn_disabled() {
+    for config in CONFIGS {
+        let mut harness =
+            Harness::<f64>::window(config).options(|slider| slider.read_only(true).style(style()));
+        let output = harness.frame(Vec::new());
+        assert_eq!(fills(output), [Color32::GRAY; 4], "{config:?}");
+
+        let mut harness = Harness::<f64>::window(config).options(|slider| slider.style(style()));
+        harness.enabled = false;
+        let output = harness.frame(Vec::new());
+        // egui fades out everything in a disabled ui
+        let alpha = harness.ctx().global_style().visuals.disabled_alpha();
+        let disabled = Color32::YELLOW.gamma_multiply(alpha);
+        assert_eq!(fills(output), [disabled; 4], "{config:?}");
+    }
+}
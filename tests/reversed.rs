@@ -0,0 +1,104 @@
+//! Tests of reversed ranges, which go from high to low.
+//!
+//! A slider over a reversed range looks like one over the forward range with mirrored values,
+//! so the same input has to lead to the mirrored selection.
+
+mod common;
+
+use common::{assert_near, Config, Harness, CONFIGS};
+use egui::{Pos2, SliderOrientation, Vec2};
+use egui_double_slider::DoubleSlider;
+
+// The value of the forward range `1..=100` at the position of `value` in the reversed range `100..=1`
+fn mirror(value: f64, config: Config) -> f64 {
+    if config.logarithmic {
+        100.0 / value
+    } else {
+        101.0 - value
+    }
+}
+
+fn unseparated(slider: DoubleSlider<'_, f64>) -> DoubleSlider<'_, f64> {
+    slider.separation_distance(0.0)
+}
+
+// Applies `input` to a slider at `20..=80` in `100..=1` and to one with the mirrored values in `1..=100`,
+// with the positions of the lower value and the middle of the reversed slider.
+// The separation distance is not mirrored, so there is none and the input must not push the values against the ends.
+fn check_mirrored(what: &str, input: impl Fn(&mut Harness<f64>, Pos2, Pos2)) {
+    for config in CONFIGS {
+        let mut reversed = Harness::new(20.0, 80.0, 100.0..=1.0, config).options(unseparated);
+        let mut forward = Harness::new(
+            mirror(80.0, config),
+            mirror(20.0, config),
+            1.0..=100.0,
+            config,
+        )
+        .options(unseparated);
+        let lower = reversed.point(20.0);
+        let middle = reversed.point(50.0);
+        input(&mut reversed, lower, middle);
+        input(&mut forward, lower, middle);
+
+        let (low, high) = reversed.values();
+        let (forward_low, forward_high) = forward.values();
+        assert_ne!((low, high), (20.0, 80.0), "{what} ({config:?})");
+        assert!(low < high, "{what}: {low}, {high} ({config:?})");
+        assert_near(
+            low,
+            mirror(forward_high, config),
+            reversed.resolution(low),
+            &format!("{what}, lower value"),
+            config,
+        );
+        assert_near(
+            high,
+            mirror(forward_low, config),
+            reversed.resolution(high),
+            &format!("{what}, upper value"),
+            config,
+        );
+    }
+}
+
+#[test]
+fn the_lower_value_is_at_the_end() {
+    for config in CONFIGS {
+        let harness = Harness::<f64>::new(20.0, 80.0, 100.0..=1.0, config);
+        let (low, high) = (harness.point(20.0), harness.point(80.0));
+        match config.orientation {
+            SliderOrientation::Horizontal => assert!(low.x > high.x, "{config:?}"),
+            SliderOrientation::Vertical => assert!(low.y < high.y, "{config:?}"),
+        }
+    }
+}
+
+#[test]
+fn dragging_the_lower_point() {
+    check_mirrored("dragging the lower point", |harness, lower, _| {
+        harness.drag(lower, harness.along(lower, 15.0));
+    });
+    check_mirrored("dragging the lower point back", |harness, lower, _| {
+        harness.drag(lower, harness.along(lower, -15.0));
+    });
+}
+
+#[test]
+fn dragging_the_in_between_part() {
+    check_mirrored("dragging the in-between part", |harness, _, middle| {
+        harness.drag(middle, harness.along(middle, 15.0));
+    });
+}
+
+#[test]
+fn scrolling() {
+    check_mirrored("scrolling", |harness, _, _| {
+        harness.scroll(Vec2::new(0.0, 20.0));
+    });
+}
+
+#[test]
+fn zooming() {
+    check_mirrored("zooming in", |harness, _, _| harness.zoom(1.5));
+    check_mirrored("zooming out", |harness, _, _| harness.zoom(0.8));
+}
@@ -0,0 +1,93 @@
+//! Tests of rounding the values to a number of decimals or significant digits.
+
+mod common;
+
+use common::{texts, Harness, CONFIGS};
+use egui::Vec2;
+
+// whether a value has at most `decimals` decimals, in its shortest representation
+fn has_decimals(value: f32, decimals: usize) -> bool {
+    let text = value.to_string();
+    text.split_once('.')
+        .is_none_or(|(_, fraction)| fraction.len() <= decimals)
+}
+
+#[test]
+fn dragged_values_are_rounded() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f32>::new(10.0, 90.0, 1.0..=100.0, config)
+            .options(|slider| slider.fixed_decimals(2));
+        let from = harness.point(harness.low);
+        harness.drag(from, harness.along(from, 37.3));
+        let from = harness.point(harness.high);
+        harness.drag(from, harness.along(from, -23.1));
+
+        assert_ne!(harness.values(), (10.0, 90.0), "{config:?}");
+        assert!(has_decimals(harness.low, 2), "{} {config:?}", harness.low);
+        assert!(has_decimals(harness.high, 2), "{} {config:?}", harness.high);
+    }
+}
+
+#[test]
+fn scrolled_and_zoomed_values_are_rounded() {
+    for config in CONFIGS {
+        let mut harness = Harness::<f32>::new(20.0, 40.0, 1.0..=100.0, config)
+            .options(|slider| slider.max_decimals(1));
+        harness.scroll(Vec2::new(300.0, 0.0));
+        assert!(harness.low > 20.0, "{config:?}");
+        assert!(has_decimals(harness.low, 1), "{} {config:?}", harness.low);
+        assert!(has_decimals(harness.high, 1), "{} {config:?}", harness.high);
+
+        harness.zoom(1.5);
+        assert!(has_decimals(harness.low, 1), "{} {config:?}", harness.low);
+        assert!(has_decimals(harness.high, 1), "{} {config:?}", harness.high);
+    }
+}
+
+#[test]
+fn small_scroll_steps_add_up() {
+    for config in CONFIGS.into_iter().filter(|config| !config.logarithmic) {
+        // each frame of smooth scrolling moves the values by less than the rounding
+        let mut harness = Harness::<f32>::new(20.0, 40.0, 0.0..=100.0, config)
+            .options(|slider| slider.max_decimals(0));
+        harness.scroll(Vec2::new(200.0, 0.0));
+
+        assert_eq!(harness.values(), (22.0, 42.0), "{config:?}");
+    }
+}
+
+#[test]
+fn significant_digits_follow_the_magnitude() {
+    for config in CONFIGS.into_iter().filter(|config| config.logarithmic) {
+        let mut harness = Harness::<f64>::new(0.001, 1000.0, 0.0001..=10000.0, config)
+            .options(|slider| slider.significant_digits(2));
+        let from = harness.point(harness.low);
+        harness.drag(from, harness.along(from, 5.7));
+        let from = harness.point(harness.high);
+        harness.drag(from, harness.along(from, -11.3));
+
+        for value in [harness.low, harness.high] {
+            let scale = 10f64.powi(1 - value.log10().floor() as i32);
+            let digits = value * scale;
+            assert!((digits - digits.round()).abs() < 1e-9, "{value} {config:?}");
+        }
+    }
+}
+
+#[test]
+fn values_set_by_the_application_are_kept() {
+    let mut harness = Harness::<f64>::new(1.23456, 7.89012, 0.0..=10.0, CONFIGS[0])
+        .options(|slider| slider.max_decimals(1));
+    harness.frame(Vec::new());
+    assert_eq!(harness.values(), (1.23456, 7.89012));
+}
+
+#[test]
+fn labels_show_the_decimals() {
+    let mut harness = Harness::<f64>::new(2.0, 7.5, 0.0..=10.0, CONFIGS[0])
+        .options(|slider| slider.value_labels(true).fixed_decimals(2));
+    let output = harness.frame(Vec::new());
+    let mut texts = texts(&output);
+    texts.sort();
+    assert_eq!(texts, ["2.00", "7.50"]);
+}